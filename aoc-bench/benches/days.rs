@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use aoc_common::{Generate, GeneratorConfig, InputSource, Solution};
+use day03::bitslice::{count_ones, count_ones_scalar};
+use day03::report::Diagnostic;
+
+/// Times parsing and both parts of a day separately on its real input.
+/// The parts are measured on an input that was parsed once beforehand
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    // Benchmarks run in the directory of this crate, the inputs are relative to the workspace root
+    let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).join(S::DEFAULT_INPUT);
+    let input = InputSource::File(path).read(S::DEFAULT_INPUT)
+        .unwrap_or_else(|error| panic!("{}", error));
+    let parsed = S::parse(&input)
+        .unwrap_or_else(|error| panic!("Malformed input of day {:02}, {}", S::DAY, error));
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+/// Compares both ways of counting the columns of a day03 report with a million lines
+fn bench_day03_columns(c: &mut Criterion) {
+    let input = day03::Day03::generate(&GeneratorConfig::new(1000, 0));
+    let report = match day03::Day03::parse(&input).unwrap() {
+        Diagnostic::Narrow(report) => report,
+        _ => unreachable!("The generated numbers fit into 64 bits"),
+    };
+
+    let mut group = c.benchmark_group("day03-columns");
+    group.bench_function("bitsliced", |b| b.iter(|| count_ones(black_box(&report.numbers), report.width)));
+    group.bench_function("scalar", |b| b.iter(|| count_ones_scalar(black_box(&report.numbers), report.width)));
+    group.finish();
+}
+
+criterion_group!(days,
+    bench_day::<day01::Day01>,
+    bench_day::<day02::Day02>,
+    bench_day::<day03::Day03>,
+    bench_day::<day04::Day04>,
+    bench_day::<day05::Day05>,
+    bench_day::<day06::Day06>,
+    bench_day::<day07::Day07>,
+    bench_day::<day08::Day08>,
+    bench_day::<day09::Day09>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day03_columns,
+);
+criterion_main!(days);
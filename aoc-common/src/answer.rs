@@ -0,0 +1,70 @@
+use std::fmt::{Display, Formatter};
+
+use crate::Grid;
+
+/// The result of one part. Most puzzles are answered with a number,
+/// some with text like the letters drawn by day 13
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+/// Why a part has no answer, although the parser accepted the input.
+/// E.g. a bingo game that no board wins
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NoAnswer(pub String);
+
+impl NoAnswer {
+    pub fn new(reason: impl Into<String>) -> NoAnswer {
+        NoAnswer(reason.into())
+    }
+}
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(i64::try_from(n).expect("Answer does not fit into an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<Grid<char>> for Answer {
+    fn from(grid: Grid<char>) -> Self {
+        // Without the line break after the last row
+        Answer::Text(grid.to_string().trim_end().to_string())
+    }
+}
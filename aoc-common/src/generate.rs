@@ -0,0 +1,47 @@
+//! Random puzzle inputs of any size, to stress the solvers and compare implementations
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::solution::Solution;
+
+/// How much input to generate and from which seed.
+/// A scale of 1 gives about the size of the real puzzle input
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GeneratorConfig {
+    pub scale: usize,
+    pub seed: u64,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        GeneratorConfig { scale: 1, seed: 0 }
+    }
+}
+
+impl GeneratorConfig {
+    pub fn new(scale: usize, seed: u64) -> GeneratorConfig {
+        assert!(scale > 0, "The scale has to be at least 1");
+        GeneratorConfig { scale, seed }
+    }
+
+    /// The same seed yields the same numbers on every platform
+    pub fn rng(&self) -> ChaCha8Rng {
+        ChaCha8Rng::seed_from_u64(self.seed)
+    }
+
+    /// `count` items of the real input, multiplied by the scale
+    pub fn scaled(&self, count: usize) -> usize {
+        count * self.scale
+    }
+
+    /// The side length of a square that grows in area with the scale
+    pub fn scaled_side(&self, side: usize) -> usize {
+        (side as f64 * (self.scale as f64).sqrt()).round() as usize
+    }
+}
+
+/// A day that can generate valid inputs for its solver
+pub trait Generate: Solution {
+    fn generate(config: &GeneratorConfig) -> String;
+}
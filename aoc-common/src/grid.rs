@@ -0,0 +1,302 @@
+use std::fmt::{Display, Formatter};
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, Iter};
+
+use itertools::Itertools;
+
+use crate::parse::{ParseError, Span};
+
+/// Offsets of the horizontal and vertical neighbours
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the horizontal, vertical and diagonal neighbours
+const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, 0), (1, 0), (0, -1), (0, 1),
+    (-1, -1), (1, 1), (-1, 1), (1, -1),
+];
+
+/// A rectangular grid stored row-major in a flat `Vec`.
+/// Coordinates are given as `(x, y)` with `x` being the column and `y` the row,
+/// `(0, 0)` is the upper left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from row-major cells. Panics if `cells` can not be split into rows of `width`
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "cells do not form rows of width {}", width);
+        let height = cells.len() / width;
+        Grid { cells, width, height }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+        where T: Clone {
+        Grid { cells: vec![value; width * height], width, height }
+    }
+
+    /// Parses a grid with one row per line, converting every character with `cell`.
+    /// `expected` describes a valid cell for the error message
+    pub fn parse_with<F>(input: &str, expected: &str, mut cell: F) -> Result<Grid<T>, ParseError>
+        where F: FnMut(char) -> Option<T> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in Span::lines(input) {
+            let mut found = 0;
+            for (c, span) in line.chars() {
+                if width == Some(found) {
+                    return Err(span.error("the end of the row"));
+                }
+                cells.push(cell(c).ok_or_else(|| span.error(expected))?);
+                found += 1;
+            }
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found =>
+                    return Err(line.end().error(format!("{} cells in the row", expected))),
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid { cells, width, height }),
+            _ => Err(ParseError::at_end_of(input, "a row of cells")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Converts coordinates into an index of `cells()`. Does not check the bounds
+    pub fn index_of(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn coords_of(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[self.index_of(x, y)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            let index = self.index_of(x, y);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterates over all cells together with their coordinates
+    pub fn iter_coords(&self) -> impl Iterator<Item=((usize, usize), &T)> {
+        self.cells.iter().enumerate().map(move |(i, c)| (self.coords_of(i), c))
+    }
+
+    /// The horizontal and vertical neighbours that lie inside the grid
+    pub fn neighbours4(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(x, y, self.width, self.height, &ORTHOGONAL)
+    }
+
+    /// The horizontal, vertical and diagonal neighbours that lie inside the grid
+    pub fn neighbours8(&self, x: usize, y: usize) -> Neighbours {
+        Neighbours::new(x, y, self.width, self.height, &ALL_DIRECTIONS)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(x < self.width, "column {} is outside of the {}x{} grid", x, self.width, self.height);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=StepBy<Iter<'_, T>>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+        where F: FnMut(&T) -> U {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a map of decimal digits like `2199943210`
+    pub fn parse_digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse_with(input, "a digit", |c| c.to_digit(10))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, "a character", Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        assert!(self.contains(x, y), "({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        &self.cells[self.index_of(x, y)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        assert!(self.contains(x, y), "({}, {}) is outside of the {}x{} grid", x, y, self.width, self.height);
+        let index = self.index_of(x, y);
+        &mut self.cells[index]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().format(""))?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterates over the coordinates around a cell, skipping those outside of the grid
+pub struct Neighbours {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    offsets: &'static [(isize, isize)],
+    counter: usize,
+}
+
+impl Neighbours {
+    fn new(x: usize, y: usize, width: usize, height: usize, offsets: &'static [(isize, isize)]) -> Neighbours {
+        Neighbours { x, y, width, height, offsets, counter: 0 }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(&(dx, dy)) = self.offsets.get(self.counter) {
+            self.counter += 1;
+            let x = self.x.checked_add_signed(dx);
+            let y = self.y.checked_add_signed(dy);
+            if let (Some(x), Some(y)) = (x, y) {
+                if x < self.width && y < self.height {
+                    return Some((x, y));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits("123\n456\n").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse_digits("12\n3").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 2, "2 cells in the row"));
+        let error = Grid::parse_digits("12\n345").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 3, "\"5\""));
+        let error = Grid::parse_digits("12\n3x").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 2, "a digit"));
+        assert!(Grid::parse_digits("").is_err());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(grid.neighbours4(0, 0).collect_vec(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours8(1, 1).count(), 8);
+        assert_eq!(grid.neighbours8(2, 2).collect_vec(), vec![(1, 2), (2, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from_vec(3, (0..6).collect_vec());
+        assert_eq!(grid.row(1), &[3, 4, 5]);
+        assert_eq!(grid.column(2).copied().collect_vec(), vec![2, 5]);
+        assert_eq!(grid.columns().map(|c| c.sum::<i32>()).collect_vec(), vec![3, 5, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside of the 3x2 grid")]
+    fn test_column_out_of_bounds() {
+        let _ = Grid::from_vec(3, (0..6).collect_vec()).column(3);
+    }
+}
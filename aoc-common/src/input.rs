@@ -0,0 +1,103 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Names the directory the default inputs are looked up in instead of the working directory
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the default input `path` is at runtime: below the directory named by `AOC_INPUT_DIR`,
+/// or relative to the working directory without it
+pub fn default_path(path: &str) -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => Path::new(&dir).join(path),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The input file of the day, see `Solution::DEFAULT_INPUT`
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    error: io::Error,
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Default => write!(f, "default input"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not read input from {}: {}", self.source, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl InputSource {
+    /// Interprets a command line argument: `-` is stdin, everything else a path
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the whole input. `default` is the path used for `InputSource::Default`, see `default_path`
+    pub fn read(&self, default: &str) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Default => fs::read_to_string(default_path(default)),
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|error| self.error(default, error))
+    }
+
+    /// Opens the input to read it bit by bit instead of all at once
+    pub fn open(&self, default: &str) -> Result<Box<dyn BufRead>, InputError> {
+        let result = match self {
+            InputSource::Default => fs::File::open(default_path(default)),
+            InputSource::File(path) => fs::File::open(path),
+            InputSource::Stdin => return Ok(Box::new(io::stdin().lock())),
+        };
+        match result {
+            Ok(file) => Ok(Box::new(BufReader::new(file))),
+            Err(error) => Err(self.error(default, error)),
+        }
+    }
+
+    /// An error while reading from this source, e.g. from the reader returned by `open`
+    pub fn error(&self, default: &str, error: io::Error) -> InputError {
+        InputError {
+            source: match self {
+                InputSource::Default => InputSource::File(default_path(default)),
+                other => other.clone(),
+            },
+            error,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Building blocks shared by all days of the workspace.
+
+pub mod answer;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod output;
+pub mod parse;
+pub mod solution;
+pub mod testing;
+
+pub use answer::{Answer, NoAnswer};
+pub use generate::{Generate, GeneratorConfig};
+pub use grid::Grid;
+pub use input::InputSource;
+pub use output::OutputFormat;
+pub use parse::{ParseError, Span};
+pub use solution::{Day, Part, Solution};
@@ -0,0 +1,77 @@
+//! Progress and debug output of the solvers. Everything is written to stderr so that stdout
+//! only contains the answers. Nothing is printed unless the verbosity is raised, e.g. with `-v`
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet = 0,
+    /// Intermediate results like the values that make up an answer
+    Progress = 1,
+    /// State dumps like the board after every step
+    Debug = 2,
+    /// Every single step of a search
+    Trace = 3,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Quiet as u8);
+
+impl Verbosity {
+    /// The verbosity for the number of `v`s given on the command line
+    pub fn from_count(count: usize) -> Verbosity {
+        match count {
+            0 => Verbosity::Quiet,
+            1 => Verbosity::Progress,
+            2 => Verbosity::Debug,
+            _ => Verbosity::Trace,
+        }
+    }
+
+    /// Parses flags like `-v`, `-vv` and `--verbose`
+    pub fn from_flag(flag: &str) -> Option<Verbosity> {
+        if flag == "--verbose" {
+            return Some(Verbosity::Progress);
+        }
+        let vs = flag.strip_prefix('-')?;
+        if !vs.is_empty() && vs.chars().all(|c| c == 'v') {
+            Some(Verbosity::from_count(vs.len()))
+        } else {
+            None
+        }
+    }
+}
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(verbosity: Verbosity) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= verbosity as u8
+}
+
+#[macro_export]
+macro_rules! progress {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Progress) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Verbosity::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
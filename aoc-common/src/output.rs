@@ -0,0 +1,156 @@
+//! How reports are printed: readable text for humans or one JSON record per part for scripts
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use serde::{Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+use crate::answer::Answer;
+use crate::solution::{DayReport, Part};
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// One JSON object per line and part
+    Json,
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_i64(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+/// The JSON record of a single part. Times are in nanoseconds.
+/// A part without an answer has a `null` answer and the reason as `error`
+#[derive(Serialize)]
+struct PartRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    parse_time_ns: u128,
+    time_ns: u128,
+    input_hash: &'a str,
+}
+
+/// The SHA-256 of the input as hex, to tell which input an answer belongs to
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+impl DayReport {
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Text => self.to_string(),
+            OutputFormat::Json => self.parts.iter().map(|part| {
+                let record = PartRecord {
+                    day: self.day,
+                    part: part.part.number(),
+                    answer: part.answer.as_ref().ok(),
+                    error: part.answer.as_ref().err().map(ToString::to_string),
+                    parse_time_ns: self.parse_time.as_nanos(),
+                    time_ns: part.time.as_nanos(),
+                    input_hash: &self.input_hash,
+                };
+                serde_json::to_string(&record).expect("Reports can always be serialized") + "\n"
+            }).collect(),
+        }
+    }
+}
+
+fn millis(time: Duration) -> String {
+    format!("{:.3} ms", time.as_secs_f64() * 1000.0)
+}
+
+/// A table with the parse and solve times of every day and their totals.
+/// Parts that were not solved are shown as `-`
+pub fn summary_table(reports: &[DayReport]) -> String {
+    let mut table = String::new();
+    writeln!(table, "{:<5} {:>12} {:>12} {:>12} {:>12}", "Day", "Parse", "Part 1", "Part 2", "Total").unwrap();
+
+    let mut totals = [Duration::ZERO; 4];
+    for report in reports {
+        let part_time = |part: Part| report.parts.iter().find(|p| p.part == part).map(|p| p.time);
+        let times = [Some(report.parse_time), part_time(Part::One), part_time(Part::Two)];
+        let total = times.iter().flatten().sum::<Duration>();
+
+        write!(table, "{:<5}", format!("{:02}", report.day)).unwrap();
+        for (i, time) in times.iter().chain([&Some(total)]).enumerate() {
+            totals[i] += time.unwrap_or_default();
+            write!(table, " {:>12}", time.map_or("-".to_string(), millis)).unwrap();
+        }
+        table.push('\n');
+    }
+
+    write!(table, "{:<5}", "Total").unwrap();
+    for total in totals {
+        write!(table, " {:>12}", millis(total)).unwrap();
+    }
+    table.push('\n');
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::answer::NoAnswer;
+    use crate::solution::{Part, PartReport};
+
+    use super::*;
+
+    #[test]
+    fn test_json_records() {
+        let report = DayReport {
+            day: 13,
+            parse_time: Duration::from_nanos(5),
+            parts: vec![
+                PartReport { part: Part::One, answer: Ok(Answer::Number(17)), time: Duration::from_nanos(7) },
+                PartReport { part: Part::Two, answer: Ok(Answer::Text("#.\n.#".into())), time: Duration::from_nanos(9) },
+            ],
+            input_hash: input_hash(""),
+        };
+        let hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert_eq!(report.render(OutputFormat::Json), format!(
+            "{{\"day\":13,\"part\":1,\"answer\":17,\"parse_time_ns\":5,\"time_ns\":7,\"input_hash\":\"{0}\"}}\n\
+             {{\"day\":13,\"part\":2,\"answer\":\"#.\\n.#\",\"parse_time_ns\":5,\"time_ns\":9,\"input_hash\":\"{0}\"}}\n",
+            hash));
+    }
+
+    #[test]
+    fn test_missing_answer() {
+        let report = DayReport {
+            day: 4,
+            parse_time: Duration::from_nanos(5),
+            parts: vec![PartReport { part: Part::Two, answer: Err(NoAnswer::new("board 2 never wins")), time: Duration::from_nanos(7) }],
+            input_hash: String::new(),
+        };
+        assert!(!report.is_complete());
+        assert_eq!(report.render(OutputFormat::Json),
+                   "{\"day\":4,\"part\":2,\"answer\":null,\"error\":\"board 2 never wins\",\"parse_time_ns\":5,\"time_ns\":7,\"input_hash\":\"\"}\n");
+        assert!(report.to_string().contains("Part 2: no answer, board 2 never wins"));
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let report = DayReport {
+            day: 7,
+            parse_time: Duration::from_micros(500),
+            parts: vec![PartReport { part: Part::Two, answer: Ok(Answer::Number(168)), time: Duration::from_millis(2) }],
+            input_hash: String::new(),
+        };
+        assert_eq!(summary_table(&[report.clone(), report]), "\
+Day          Parse       Part 1       Part 2        Total
+07        0.500 ms            -     2.000 ms     2.500 ms
+07        0.500 ms            -     2.000 ms     2.500 ms
+Total     1.000 ms     0.000 ms     4.000 ms     5.000 ms
+");
+    }
+}
@@ -0,0 +1,174 @@
+//! Parsing helpers that remember where in the input a piece of text came from,
+//! so that malformed input can be reported with line and column instead of a panic.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Describes what was expected at a position of the input and what was found instead
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column (in characters) of the line, starting at 1
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    line_text: String,
+    width: usize,
+}
+
+impl ParseError {
+    /// An error for something that is missing once the whole input has been read
+    pub fn at_end_of(input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: input.lines().count() + 1,
+            column: 1,
+            expected: expected.into(),
+            found: "end of input".to_string(),
+            line_text: String::new(),
+            width: 1,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, self.found)?;
+        let number = self.line.to_string();
+        writeln!(f, "{} | {}", number, self.line_text)?;
+        write!(f, "{} | {}{}", " ".repeat(number.len()), " ".repeat(self.column - 1), "^".repeat(self.width))
+    }
+}
+
+impl Error for ParseError {}
+
+/// A slice of one line of the input that knows its position
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    line_text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// Splits the input into lines, the first line is line 1
+    pub fn lines(input: &'a str) -> impl Iterator<Item=Span<'a>> {
+        input.lines().enumerate().map(|(i, line)| Span { text: line, line: i + 1, line_text: line })
+    }
+
+    /// A single line of an input that is read line by line, `line` starts at 1
+    pub fn line_of(text: &'a str, line: usize) -> Span<'a> {
+        Span { text, line, line_text: text }
+    }
+
+    /// `text` has to be a part of this span
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        Span { text, ..*self }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        let offset = self.text.as_ptr() as usize - self.line_text.as_ptr() as usize;
+        self.line_text[..offset].chars().count() + 1
+    }
+
+    /// The empty span right after this one
+    pub fn end(&self) -> Span<'a> {
+        self.sub(&self.text[self.text.len()..])
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    pub fn split(self, separator: char) -> impl Iterator<Item=Span<'a>> {
+        self.text.split(separator).map(move |s| self.sub(s))
+    }
+
+    pub fn split_whitespace(self) -> impl Iterator<Item=Span<'a>> {
+        self.text.split_whitespace().map(move |s| self.sub(s))
+    }
+
+    /// Every character as its own span
+    pub fn chars(self) -> impl Iterator<Item=(char, Span<'a>)> {
+        self.text.char_indices()
+            .map(move |(i, c)| (c, self.sub(&self.text[i..i + c.len_utf8()])))
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(separator) {
+            Some((a, b)) => Ok((self.sub(a), self.sub(b))),
+            None => Err(self.error(format!("{:?}", separator))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("{:?}", prefix))),
+        }
+    }
+
+    /// Parses the whole span, `expected` describes the value for the error message
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = if self.text.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("{:?}", self.text)
+        };
+        ParseError {
+            line: self.line,
+            column: self.column(),
+            expected: expected.into(),
+            found,
+            line_text: self.line_text.to_string(),
+            width: self.text.chars().count().max(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let input = "forward 5\ndown x7\n";
+        let line = Span::lines(input).nth(1).unwrap();
+        let (_, amount) = line.split_once(" ").unwrap();
+        let error = amount.parse::<i32>("a number").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.to_string(), "line 2, column 6: expected a number, found \"x7\"\n2 | down x7\n  |      ^^");
+    }
+
+    #[test]
+    fn test_missing_separator() {
+        let line = Span::lines("0,9 - 5,9").next().unwrap();
+        let error = line.split_once(" -> ").unwrap_err();
+        assert_eq!(error.expected, "\" -> \"");
+        assert_eq!(error.column, 1);
+
+        let error = Span::lines("forward").next().unwrap().split_once(" ").unwrap_err();
+        assert_eq!(error.to_string().lines().next().unwrap(), "line 1, column 1: expected \" \", found \"forward\"");
+
+        let (_, rest) = Span::lines("1,").next().unwrap().split_once(",").unwrap();
+        let error = rest.parse::<i32>("a coordinate").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (3, "end of line"));
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt::{Display, Formatter};
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+use crate::answer::{Answer, NoAnswer};
+use crate::generate::{Generate, GeneratorConfig};
+use crate::input::{InputError, InputSource};
+use crate::log::{set_verbosity, Verbosity};
+use crate::output::{input_hash, OutputFormat};
+use crate::parse::ParseError;
+
+/// A puzzle of one day. The input is parsed once and shared by both parts
+pub trait Solution {
+    /// Day of december the puzzle was released
+    const DAY: u8;
+    /// Path of the puzzle input that is used when no other input is given, relative to the
+    /// workspace root. It is resolved when the input is read, see `input::default_path`
+    const DEFAULT_INPUT: &'static str;
+
+    type Input;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    /// `NoAnswer` if the puzzle cannot be solved for the parsed input
+    fn part1(input: &Self::Input) -> Result<Self::Answer1, NoAnswer>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2, NoAnswer>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Result<Answer, NoAnswer>,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+    /// See [`input_hash`]
+    pub input_hash: String,
+}
+
+impl DayReport {
+    /// Whether every part that was run has an answer
+    pub fn is_complete(&self) -> bool {
+        self.parts.iter().all(|part| part.answer.is_ok())
+    }
+}
+
+impl Display for DayReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Day {:02} (parsed in {:?})", self.day, self.parse_time)?;
+        for part in &self.parts {
+            let answer = match &part.answer {
+                Ok(answer) => answer.to_string(),
+                Err(reason) => {
+                    writeln!(f, "  Part {}: no answer, {} ({:?})", part.part, reason, part.time)?;
+                    continue;
+                }
+            };
+            // Multi line answers (e.g. letters drawn on a display) start on their own line
+            if answer.contains('\n') {
+                writeln!(f, "  Part {} ({:?}):", part.part, part.time)?;
+                for line in answer.lines() {
+                    writeln!(f, "    {}", line)?;
+                }
+            } else {
+                writeln!(f, "  Part {}: {} ({:?})", part.part, answer, part.time)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the input and solves the requested parts, measuring how long each step took
+pub fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts.iter().map(|&part| {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => S::part1(&parsed).map(Into::into),
+            Part::Two => S::part2(&parsed).map(Into::into),
+        };
+        PartReport { part, answer, time: start.elapsed() }
+    }).collect();
+
+    Ok(DayReport { day: S::DAY, parse_time, parts, input_hash: input_hash(input) })
+}
+
+/// Entry point for the binaries of the single days.
+/// Takes the input file (`-` for stdin), `-v` flags and `--json`, the default input is used without a file
+pub fn run_main<S: Solution>() {
+    let mut source = InputSource::Default;
+    let mut format = OutputFormat::Text;
+    for arg in std::env::args().skip(1) {
+        if arg == "--json" {
+            format = OutputFormat::Json;
+            continue;
+        }
+        match Verbosity::from_flag(&arg) {
+            Some(verbosity) => set_verbosity(verbosity),
+            None => source = InputSource::from_arg(&arg),
+        }
+    }
+
+    let input = read_or_exit(&source, S::DEFAULT_INPUT);
+    match run::<S>(&input, &Part::BOTH) {
+        Ok(report) => {
+            print!("{}", report.render(format));
+            if !report.is_complete() {
+                exit(1);
+            }
+        }
+        Err(error) => {
+            eprintln!("Malformed input in {}, {}", source, error);
+            exit(1);
+        }
+    }
+}
+
+/// For the other modes of the binaries of the single days: reads and parses the input like `run_main`,
+/// printing what went wrong and exiting if either fails
+pub fn parse_main<S: Solution>(source: &InputSource) -> S::Input {
+    let input = read_or_exit(source, S::DEFAULT_INPUT);
+    S::parse(&input).unwrap_or_else(|error| {
+        eprintln!("Malformed input in {}, {}", source, error);
+        exit(1);
+    })
+}
+
+fn read_or_exit(source: &InputSource, default: &str) -> String {
+    source.read(default).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        exit(1);
+    })
+}
+
+/// A `Solution` with its type erased, so that all days can be stored in one registry
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub day: u8,
+    pub default_input: &'static str,
+    runner: fn(&str, &[Part]) -> Result<DayReport, ParseError>,
+    generator: fn(&GeneratorConfig) -> String,
+}
+
+impl Day {
+    pub const fn of<S: Solution + Generate>() -> Day {
+        Day {
+            day: S::DAY,
+            default_input: S::DEFAULT_INPUT,
+            runner: run::<S>,
+            generator: S::generate,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<DayReport, ParseError> {
+        (self.runner)(input, parts)
+    }
+
+    pub fn read_input(&self, source: &InputSource) -> Result<String, InputError> {
+        source.read(self.default_input)
+    }
+
+    /// A random input for this day
+    pub fn generate(&self, config: &GeneratorConfig) -> String {
+        (self.generator)(config)
+    }
+}
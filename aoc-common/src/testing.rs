@@ -0,0 +1,107 @@
+//! Helpers to check the solvers against known answers, both for the samples of the puzzle
+//! descriptions and for the real inputs listed in an answers file
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::answer::Answer;
+use crate::parse::{ParseError, Span};
+use crate::solution::{run, Part, Solution};
+
+/// Solves both parts of `input` and compares them with the expected answers.
+/// Trailing whitespace of text answers is ignored
+pub fn check_answers<S: Solution>(input: &str, part1: impl Into<Answer>, part2: impl Into<Answer>) {
+    let report = match run::<S>(input, &Part::BOTH) {
+        Ok(report) => report,
+        Err(error) => panic!("Malformed input, {}", error),
+    };
+    let expected = [part1.into(), part2.into()];
+    for (part, expected) in report.parts.iter().zip(&expected) {
+        let answer = part.answer.as_ref()
+            .unwrap_or_else(|reason| panic!("No answer for day {:02} part {}, {}", S::DAY, part.part, reason));
+        assert_eq!(answer.to_string().trim_end(), expected.to_string().trim_end(),
+                   "Wrong answer for day {:02} part {}", S::DAY, part.part);
+    }
+}
+
+/// Declares one test per sample input that checks the answers of both parts.
+/// The files are included relative to the file the macro is used in
+///
+/// ```ignore
+/// aoc_common::sample_tests! {
+///     Day12;
+///     small: "test.txt" => 10, 36;
+///     larger: "test2.txt" => 19, 103;
+/// }
+/// ```
+#[macro_export]
+macro_rules! sample_tests {
+    ($solution:ty; $($(#[$attr:meta])* $name:ident: $file:literal => $part1:expr, $part2:expr;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                $crate::testing::check_answers::<$solution>(include_str!($file), $part1, $part2);
+            }
+        )*
+    };
+}
+
+/// Answers of the real inputs. Every line holds the day, the part and the answer,
+/// e.g. `7 2 105461913`. Line breaks of text answers are written as `\n`,
+/// empty lines and lines starting with `#` are ignored
+#[derive(Debug, Clone, Default)]
+pub struct KnownAnswers {
+    answers: HashMap<(u8, Part), String>,
+}
+
+impl KnownAnswers {
+    pub fn parse(content: &str) -> Result<KnownAnswers, ParseError> {
+        let mut answers = HashMap::new();
+        for line in Span::lines(content).map(|l| l.trim()) {
+            if line.is_empty() || line.as_str().starts_with('#') {
+                continue;
+            }
+            let (day, rest) = line.split_once(" ")?;
+            let (part, answer) = rest.trim().split_once(" ")?;
+            let day = day.parse::<u8>("a day")?;
+            let part = match part.as_str() {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(part.error("the part 1 or 2")),
+            };
+            answers.insert((day, part), answer.trim().as_str().replace("\\n", "\n"));
+        }
+        Ok(KnownAnswers { answers })
+    }
+
+    /// Reads the answers file, `None` if there is none
+    pub fn load(path: &Path) -> Option<Result<KnownAnswers, ParseError>> {
+        let content = std::fs::read_to_string(path).ok()?;
+        Some(KnownAnswers::parse(&content))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_known_answers() {
+        let answers = KnownAnswers::parse("# day part answer\n1 1 1451\n\n13 2 #.#\\n.#.\n").unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("1451"));
+        assert_eq!(answers.get(1, Part::Two), None);
+        assert_eq!(answers.get(13, Part::Two), Some("#.#\n.#."));
+
+        let error = KnownAnswers::parse("1 3 1451").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
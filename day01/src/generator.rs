@@ -0,0 +1,20 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use rand::Rng;
+
+use crate::Day01;
+
+/// Sonar depths that mostly go down, 2000 measurements per scale
+impl Generate for Day01 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        let mut depth: i32 = rng.gen_range(100..200);
+        for _ in 0..config.scaled(2000) {
+            writeln!(input, "{}", depth).unwrap();
+            depth = (depth + rng.gen_range(-10..=20)).max(0);
+        }
+        input
+    }
+}
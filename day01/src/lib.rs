@@ -0,0 +1,42 @@
+use aoc_common::{NoAnswer, ParseError, Solution, Span};
+
+mod generator;
+pub mod stats;
+pub mod stream;
+pub mod window;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const DEFAULT_INPUT: &'static str = "day01/src/input.txt";
+
+    type Input = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Vec<i32>, ParseError> {
+        Span::lines(content).map(|l| l.trim().parse::<i32>("a depth measurement"))
+            .collect()
+    }
+
+    /// Counts how often the measurement increased
+    fn part1(numbers: &Vec<i32>) -> Result<usize, NoAnswer> {
+        Ok(window::analyze(numbers, 1).increases)
+    }
+
+    /// Counts how often the sum of a group of three measurements increased
+    fn part2(numbers: &Vec<i32>) -> Result<usize, NoAnswer> {
+        Ok(window::analyze(numbers, 3).increases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day01;
+        sample: "test.txt" => 7, 5;
+    }
+}
@@ -1,30 +1,64 @@
-use itertools::{Itertools, izip};
+use std::process::exit;
 
-fn part1() {
-    let content = include_str!("input.txt");
-    let numbers: Vec<i32> = content.lines().map(|l| l.parse::<i32>().expect("Error while parsing"))
-        .collect();
+use aoc_common::solution::parse_main;
+use aoc_common::{InputSource, Solution};
+use day01::stats::SonarStats;
+use day01::stream::analyze_reader;
+use day01::Day01;
 
-    let collect = numbers.iter().zip(numbers.iter().skip(1))
-        .map(|(i1, i2)| i2 - i1)
-        .filter(|&i| i > 0).count();
-    println!("Measurement increased {} times", collect)
+/// Prints the runs, extremes, plateaus and deltas of the depths
+fn print_stats(source: InputSource) {
+    print!("{}", SonarStats::new(&parse_main::<Day01>(&source)));
 }
 
-fn part2() {
-    let content = include_str!("input.txt");
-    let numbers: Vec<i32> = content.lines().map(|l| l.parse::<i32>().expect("Error while parsing"))
-        .collect();
+/// With `--stream [--window <n>] [--every <n>] [file|-]` the depths are analyzed while they are read
+/// and the statistics are printed every `n` measurements (100000 by default).
+/// `--stats [file|-]` prints statistics of the changes between the depths.
+/// Without either flag both parts count the increasing depths of the whole sweep
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "--stats") {
+        print_stats(args.get(1).map_or(InputSource::Default, |arg| InputSource::from_arg(arg)));
+        return;
+    }
+    if !args.iter().any(|arg| arg == "--stream") {
+        aoc_common::solution::run_main::<Day01>();
+        return;
+    }
 
-    let groups = izip!(numbers.iter(), numbers.iter().skip(1), numbers.iter().skip(2))
-        .map(|(i1, i2, i3)| i1 + i2 + i3);
-    let differences = groups.clone().zip(groups.skip(1))
-        .map(|(i1, i2)| i2 - i1)
-        .filter(|&i| i > 0).count();
-    print!("Measurement of groups increased {} times", differences)
+    let mut window = 1;
+    let mut every = 100_000;
+    let mut source = InputSource::Default;
+    let mut args = args.into_iter().filter(|arg| arg != "--stream");
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" | "--every" => {
+                let value = args.next().and_then(|v| v.parse::<usize>().ok()).filter(|&v| v > 0);
+                match value {
+                    Some(value) if arg == "--window" => window = value,
+                    Some(value) => every = value,
+                    None => {
+                        eprintln!("{} expects a number of at least 1", arg);
+                        exit(2);
+                    }
+                }
+            }
+            _ => source = InputSource::from_arg(&arg),
+        }
+    }
 
-}
-fn main() {
-    part1();
-    part2();
+    let reader = match source.open(Day01::DEFAULT_INPUT) {
+        Ok(reader) => reader,
+        Err(error) => {
+            eprintln!("{}", error);
+            exit(1);
+        }
+    };
+    match analyze_reader(reader, window, every, |stats| println!("{}", stats)) {
+        Ok(stats) => println!("Total: {}", stats),
+        Err(error) => {
+            eprintln!("{}", error);
+            exit(1);
+        }
+    }
 }
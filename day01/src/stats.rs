@@ -0,0 +1,155 @@
+//! Statistics of the changes between consecutive depth measurements
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+/// Consecutive measurements, `start` is the index of the first one
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Segment {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// A change between two measurements, `index` is the measurement after it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub index: usize,
+    pub delta: i32,
+}
+
+/// Measurements of the same depth
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Plateau {
+    pub segment: Segment,
+    pub depth: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SonarStats {
+    /// The first of the longest runs of strictly increasing measurements
+    pub longest_increase: Option<Segment>,
+    /// The largest increase from one measurement to the next
+    pub largest_jump: Option<Change>,
+    /// The largest decrease from one measurement to the next
+    pub largest_drop: Option<Change>,
+    /// All runs of at least two measurements of the same depth
+    pub plateaus: Vec<Plateau>,
+    /// How often every difference between two consecutive measurements occurs
+    pub deltas: BTreeMap<i32, usize>,
+}
+
+impl SonarStats {
+    pub fn new(depths: &[i32]) -> SonarStats {
+        let mut stats = SonarStats {
+            longest_increase: (!depths.is_empty()).then_some(Segment { start: 0, length: 1 }),
+            largest_jump: None,
+            largest_drop: None,
+            plateaus: Vec::new(),
+            deltas: BTreeMap::new(),
+        };
+        let mut increase = Segment { start: 0, length: 1 };
+        let mut plateau = Segment { start: 0, length: 1 };
+
+        for (i, pair) in depths.windows(2).enumerate() {
+            let index = i + 1;
+            let delta = pair[1] - pair[0];
+            *stats.deltas.entry(delta).or_default() += 1;
+
+            if delta > 0 && stats.largest_jump.is_none_or(|jump| delta > jump.delta) {
+                stats.largest_jump = Some(Change { index, delta });
+            }
+            if delta < 0 && stats.largest_drop.is_none_or(|drop| delta < drop.delta) {
+                stats.largest_drop = Some(Change { index, delta });
+            }
+
+            if delta > 0 {
+                increase.length += 1;
+                if stats.longest_increase.is_none_or(|longest| increase.length > longest.length) {
+                    stats.longest_increase = Some(increase);
+                }
+            } else {
+                increase = Segment { start: index, length: 1 };
+            }
+
+            if delta == 0 {
+                plateau.length += 1;
+            } else {
+                stats.end_plateau(plateau, pair[0]);
+                plateau = Segment { start: index, length: 1 };
+            }
+        }
+        if let Some(&last) = depths.last() {
+            stats.end_plateau(plateau, last);
+        }
+        stats
+    }
+
+    fn end_plateau(&mut self, segment: Segment, depth: i32) {
+        if segment.length > 1 {
+            self.plateaus.push(Plateau { segment, depth });
+        }
+    }
+}
+
+impl Display for SonarStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(run) = self.longest_increase {
+            writeln!(f, "Longest increase: {} measurements from index {}", run.length, run.start)?;
+        }
+        if let Some(jump) = self.largest_jump {
+            writeln!(f, "Largest jump: +{} at index {}", jump.delta, jump.index)?;
+        }
+        if let Some(drop) = self.largest_drop {
+            writeln!(f, "Largest drop: {} at index {}", drop.delta, drop.index)?;
+        }
+        writeln!(f, "Plateaus: {}", self.plateaus.len())?;
+        for plateau in &self.plateaus {
+            writeln!(f, "  depth {} for {} measurements from index {}",
+                     plateau.depth, plateau.segment.length, plateau.segment.start)?;
+        }
+
+        writeln!(f, "Deltas:")?;
+        let most = self.deltas.values().copied().max().unwrap_or(0);
+        for (delta, &count) in &self.deltas {
+            // The bars are scaled to at most 50 characters
+            let bar = "#".repeat((count * 50).div_ceil(most));
+            writeln!(f, "  {:>+6} {:>7} {}", delta, count, bar)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let stats = SonarStats::new(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+        assert_eq!(stats.longest_increase, Some(Segment { start: 0, length: 4 }));
+        assert_eq!(stats.largest_jump, Some(Change { index: 6, delta: 33 }));
+        assert_eq!(stats.largest_drop, Some(Change { index: 4, delta: -10 }));
+        assert!(stats.plateaus.is_empty());
+        assert_eq!(stats.deltas.iter().map(|(&d, &c)| (d, c)).collect::<Vec<_>>(),
+                   vec![(-10, 1), (-9, 1), (1, 1), (2, 1), (3, 1), (7, 1), (8, 1), (29, 1), (33, 1)]);
+    }
+
+    #[test]
+    fn test_plateaus() {
+        let stats = SonarStats::new(&[5, 5, 5, 6, 7, 7]);
+        assert_eq!(stats.plateaus, vec![
+            Plateau { segment: Segment { start: 0, length: 3 }, depth: 5 },
+            Plateau { segment: Segment { start: 4, length: 2 }, depth: 7 },
+        ]);
+        assert_eq!(stats.longest_increase, Some(Segment { start: 2, length: 3 }));
+        assert_eq!(stats.largest_drop, None);
+        assert_eq!(stats.deltas.get(&0), Some(&3));
+    }
+
+    #[test]
+    fn test_empty() {
+        let stats = SonarStats::new(&[]);
+        assert_eq!(stats.longest_increase, None);
+        assert!(stats.deltas.is_empty());
+    }
+}
@@ -0,0 +1,146 @@
+//! Analyzes depths while they are read, e.g. sonar logs too large to be loaded at once.
+//! Only the current window is kept in memory
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+use aoc_common::{ParseError, Span};
+
+use crate::window::{RollingSum, Trend};
+
+/// Statistics of all depths read so far
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct RunningStats {
+    pub measurements: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub unchanged: usize,
+    pub min: Option<i32>,
+    pub max: Option<i32>,
+    sum: i64,
+}
+
+impl RunningStats {
+    pub fn mean(&self) -> Option<f64> {
+        (self.measurements > 0).then(|| self.sum as f64 / self.measurements as f64)
+    }
+}
+
+impl Display for RunningStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} measurements, {} increases, {} decreases, {} unchanged",
+               self.measurements, self.increases, self.decreases, self.unchanged)?;
+        if let (Some(min), Some(max), Some(mean)) = (self.min, self.max, self.mean()) {
+            write!(f, ", depth {} to {} (mean {:.2})", min, max, mean)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares every window of depths with the one before as soon as it is complete
+#[derive(Debug, Clone)]
+pub struct DepthStream {
+    rolling: RollingSum,
+    previous: Option<i64>,
+    stats: RunningStats,
+}
+
+impl DepthStream {
+    pub fn new(window: usize) -> DepthStream {
+        DepthStream { rolling: RollingSum::new(window), previous: None, stats: RunningStats::default() }
+    }
+
+    /// Adds the next depth and returns the trend of the window it completed, if there was one before
+    pub fn push(&mut self, depth: i32) -> Option<Trend> {
+        let stats = &mut self.stats;
+        stats.measurements += 1;
+        stats.sum += depth as i64;
+        stats.min = Some(stats.min.map_or(depth, |min| min.min(depth)));
+        stats.max = Some(stats.max.map_or(depth, |max| max.max(depth)));
+
+        let sum = self.rolling.push(depth)?;
+        let trend = self.previous.map(|previous| Trend::between(previous, sum));
+        self.previous = Some(sum);
+        match trend {
+            Some(Trend::Increase) => stats.increases += 1,
+            Some(Trend::Decrease) => stats.decreases += 1,
+            Some(Trend::Unchanged) => stats.unchanged += 1,
+            None => {}
+        }
+        trend
+    }
+
+    pub fn stats(&self) -> &RunningStats {
+        &self.stats
+    }
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "Could not read input: {}", error),
+            StreamError::Parse(error) => write!(f, "Malformed input, {}", error),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+/// Reads one depth per line from `reader`. `on_update` is called with the statistics
+/// after every `every` measurements, the final statistics are returned
+pub fn analyze_reader<R, F>(mut reader: R, window: usize, every: usize, mut on_update: F)
+                            -> Result<RunningStats, StreamError>
+    where R: BufRead, F: FnMut(&RunningStats) {
+    let mut stream = DepthStream::new(window);
+    let mut line = String::new();
+    let mut number = 0;
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line).map_err(StreamError::Io)?;
+        if read == 0 {
+            break;
+        }
+        number += 1;
+        let depth = Span::line_of(line.trim_end_matches(['\r', '\n']), number).trim()
+            .parse::<i32>("a depth measurement")
+            .map_err(StreamError::Parse)?;
+        stream.push(depth);
+        if stream.stats().measurements.is_multiple_of(every) {
+            on_update(stream.stats());
+        }
+    }
+    Ok(*stream.stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_matches_solution() {
+        let input = include_str!("test.txt");
+        let mut updates = Vec::new();
+        let stats = analyze_reader(input.as_bytes(), 3, 4,
+                                   |stats| updates.push(stats.measurements)).unwrap();
+        assert_eq!(updates, vec![4, 8]);
+        assert_eq!((stats.increases, stats.decreases, stats.unchanged), (5, 1, 1));
+        assert_eq!((stats.min, stats.max, stats.measurements), (Some(199), Some(269), 10));
+        assert_eq!(stats.mean(), Some(225.6));
+    }
+
+    #[test]
+    fn test_stream_reports_line() {
+        let error = analyze_reader("1\n2\nthree\n".as_bytes(), 1, 10, |_| {}).unwrap_err();
+        match error {
+            StreamError::Parse(error) => assert_eq!((error.line, error.column), (3, 1)),
+            StreamError::Io(error) => panic!("Unexpected error {}", error),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Compares the sums of sliding windows over the depth measurements
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+/// The sum of the last `window` values, updated with every new value instead of summed up again
+#[derive(Debug, Clone)]
+pub struct RollingSum {
+    window: usize,
+    values: VecDeque<i32>,
+    sum: i64,
+}
+
+impl RollingSum {
+    pub fn new(window: usize) -> RollingSum {
+        assert!(window > 0, "A window has to contain at least one measurement");
+        RollingSum { window, values: VecDeque::with_capacity(window + 1), sum: 0 }
+    }
+
+    /// Adds a value and returns the sum of the window once it is full
+    pub fn push(&mut self, value: i32) -> Option<i64> {
+        self.values.push_back(value);
+        self.sum += value as i64;
+        if self.values.len() > self.window {
+            self.sum -= self.values.pop_front().unwrap() as i64;
+        }
+        (self.values.len() == self.window).then_some(self.sum)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Trend {
+    Increase,
+    Decrease,
+    Unchanged,
+}
+
+impl Trend {
+    pub fn between(previous: i64, current: i64) -> Trend {
+        match current.cmp(&previous) {
+            Ordering::Greater => Trend::Increase,
+            Ordering::Less => Trend::Decrease,
+            Ordering::Equal => Trend::Unchanged,
+        }
+    }
+}
+
+/// How the sum of each window compares to the window before
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrendReport {
+    pub increases: usize,
+    pub decreases: usize,
+    pub unchanged: usize,
+    /// Every window whose sum is larger than the one before, windows are numbered by their first measurement
+    pub increase_indices: Vec<usize>,
+}
+
+impl TrendReport {
+    /// Adds the trend of the window starting at `index` compared to the one before it
+    pub fn record(&mut self, index: usize, trend: Trend) {
+        match trend {
+            Trend::Increase => {
+                self.increases += 1;
+                self.increase_indices.push(index);
+            }
+            Trend::Decrease => self.decreases += 1,
+            Trend::Unchanged => self.unchanged += 1,
+        }
+    }
+}
+
+/// Compares the sums of all windows of `window` consecutive depths
+pub fn analyze(depths: &[i32], window: usize) -> TrendReport {
+    let mut report = TrendReport::default();
+    let mut rolling = RollingSum::new(window);
+    let mut previous = None;
+    for (i, &depth) in depths.iter().enumerate() {
+        if let Some(sum) = rolling.push(depth) {
+            if let Some(previous) = previous {
+                report.record(i + 1 - window, Trend::between(previous, sum));
+            }
+            previous = Some(sum);
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_single_measurements() {
+        assert_eq!(analyze(&SAMPLE, 1), TrendReport {
+            increases: 7,
+            decreases: 2,
+            unchanged: 0,
+            increase_indices: vec![1, 2, 3, 5, 6, 7, 9],
+        });
+    }
+
+    #[test]
+    fn test_windows_of_three() {
+        assert_eq!(analyze(&SAMPLE, 3), TrendReport {
+            increases: 5,
+            decreases: 1,
+            unchanged: 1,
+            increase_indices: vec![1, 4, 5, 6, 7],
+        });
+    }
+
+    #[test]
+    fn test_window_larger_than_input() {
+        assert_eq!(analyze(&SAMPLE, 10), TrendReport::default());
+        assert_eq!(analyze(&SAMPLE, 11), TrendReport::default());
+    }
+}
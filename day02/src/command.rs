@@ -0,0 +1,94 @@
+use std::fmt::{Display, Formatter};
+
+use aoc_common::{ParseError, Span};
+
+/// A single instruction of the planned course
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Move(Move),
+    /// Remembers the current position under a name
+    Checkpoint(String),
+    /// Goes straight back to a remembered position
+    ReturnTo(String),
+}
+
+/// The commands whose meaning depends on the movement model
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Move {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    Back(i32),
+    SetAim(i32),
+}
+
+impl Command {
+    /// Parses a line like `forward 5` or `checkpoint reef`
+    pub fn parse(line: Span) -> Result<Command, ParseError> {
+        let (keyword, argument) = line.trim().split_once(" ")?;
+        let argument = argument.trim();
+        let amount = || argument.parse::<i32>("a number");
+        match keyword.as_str() {
+            "forward" => Ok(Command::Move(Move::Forward(amount()?))),
+            "down" => Ok(Command::Move(Move::Down(amount()?))),
+            "up" => Ok(Command::Move(Move::Up(amount()?))),
+            "back" => Ok(Command::Move(Move::Back(amount()?))),
+            "set-aim" => Ok(Command::Move(Move::SetAim(amount()?))),
+            "checkpoint" => Ok(Command::Checkpoint(parse_name(argument)?)),
+            "return-to" => Ok(Command::ReturnTo(parse_name(argument)?)),
+            _ => Err(keyword.error("'forward', 'up', 'down', 'back', 'set-aim', 'checkpoint' or 'return-to'")),
+        }
+    }
+}
+
+/// Checkpoint names are single words of letters, digits, `-` and `_`
+fn parse_name(name: Span) -> Result<String, ParseError> {
+    let valid = |c: char| c.is_alphanumeric() || c == '-' || c == '_';
+    match name.chars().find(|&(c, _)| !valid(c)) {
+        Some((_, position)) => Err(position.error("a checkpoint name")),
+        None => Ok(name.as_str().to_string()),
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Move(step) => write!(f, "{}", step),
+            Command::Checkpoint(name) => write!(f, "checkpoint {}", name),
+            Command::ReturnTo(name) => write!(f, "return-to {}", name),
+        }
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Move::Forward(amount) => write!(f, "forward {}", amount),
+            Move::Down(amount) => write!(f, "down {}", amount),
+            Move::Up(amount) => write!(f, "up {}", amount),
+            Move::Back(amount) => write!(f, "back {}", amount),
+            Move::SetAim(aim) => write!(f, "set-aim {}", aim),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> Result<Command, ParseError> {
+        Command::parse(Span::lines(line).next().unwrap())
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("back 3"), Ok(Command::Move(Move::Back(3))));
+        assert_eq!(parse("set-aim -2"), Ok(Command::Move(Move::SetAim(-2))));
+        assert_eq!(parse("checkpoint reef_1"), Ok(Command::Checkpoint("reef_1".to_string())));
+        assert_eq!(parse("return-to reef_1").unwrap().to_string(), "return-to reef_1");
+
+        let error = parse("checkpoint a,b").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (13, "a checkpoint name"));
+        assert_eq!(parse("sideways 7").unwrap_err().column, 1);
+    }
+}
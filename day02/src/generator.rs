@@ -0,0 +1,32 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use rand::Rng;
+
+use crate::command::Move;
+use crate::Day02;
+
+/// 1000 commands per scale. The submarine never moves above the surface
+impl Generate for Day02 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        let mut depth = 0;
+        for _ in 0..config.scaled(1000) {
+            let amount = rng.gen_range(1..=9);
+            let command = match rng.gen_range(0..3) {
+                0 => Move::Forward(amount),
+                1 if amount <= depth => {
+                    depth -= amount;
+                    Move::Up(amount)
+                }
+                _ => {
+                    depth += amount;
+                    Move::Down(amount)
+                }
+            };
+            writeln!(input, "{}", command).unwrap();
+        }
+        input
+    }
+}
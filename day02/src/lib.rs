@@ -0,0 +1,66 @@
+use aoc_common::{progress, NoAnswer, ParseError, Solution, Span};
+
+use crate::command::Command;
+use crate::movement::{navigate, Aim, Movement, Simple};
+
+pub mod command;
+mod generator;
+pub mod movement;
+pub mod trajectory;
+
+pub struct Day02;
+
+/// Follows the commands with the given model and multiplies the final depth and horizontal position.
+/// There is no answer if a command takes the submarine above the surface or to an unknown checkpoint
+fn solve<M: Movement>(model: &M, commands: &[Command]) -> Result<i64, NoAnswer> {
+    let position = navigate(model, commands, None)
+        .map_err(|error| NoAnswer::new(format!("cannot navigate, {}", error)))?;
+    progress!("Depth: {}", position.depth);
+    progress!("Horizontal: {}", position.horizontal);
+    progress!("Aim: {}", position.aim);
+    Ok(position.depth * position.horizontal)
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const DEFAULT_INPUT: &'static str = "day02/src/input.txt";
+
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(content: &str) -> Result<Vec<Command>, ParseError> {
+        Span::lines(content).map(Command::parse).collect()
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<i64, NoAnswer> {
+        solve(&Simple, commands)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<i64, NoAnswer> {
+        solve(&Aim, commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day02;
+        sample: "test.txt" => 150, 900;
+    }
+
+    #[test]
+    fn test_courses_that_cannot_be_followed() {
+        // Parsing only checks the syntax, the parts stop at the command that cannot be followed
+        let commands = Day02::parse("down 2\nup 3\n").unwrap();
+        assert_eq!(Day02::part1(&commands),
+                   Err(NoAnswer::new("cannot navigate, command 2 'up 3' takes the submarine above the surface to depth -1")));
+        assert_eq!(Day02::part2(&commands), Ok(0));
+
+        let commands = Day02::parse("checkpoint a\nreturn-to b\n").unwrap();
+        assert_eq!(Day02::part2(&commands),
+                   Err(NoAnswer::new("cannot navigate, command 2 'return-to b' returns to a checkpoint that has not been set")));
+    }
+}
@@ -1,58 +1,75 @@
-enum Direction {
-    Forward,
-    Down,
-    Up
-}
-fn part1() {
-    let content = include_str!("input.txt");
-    let mut depth: i32 = 0;
-    let mut horizontal: i32 = 0;
+use std::process::exit;
 
-    for l in content.lines() {
-        let (direction, increment) = l.split_once(' ').unwrap();
-        match direction {
-            "forward" => {horizontal += increment.parse::<i32>().expect("Parsing error")},
-            "up" => {depth -= increment.parse::<i32>().expect("Parsing error")},
-            "down" => {depth += increment.parse::<i32>().expect("Parsing error")},
-            &_ => {panic!("This token was not expected!")}
-        }
-    }
+use aoc_common::solution::parse_main;
+use aoc_common::InputSource;
+use day02::command::Command;
+use day02::movement::{Aim, NavigationError, Simple};
+use day02::trajectory::{depth_profile, Trajectory};
+use day02::Day02;
 
-    println!("Depth: {}", depth);
-    println!("Horizontal: {}", horizontal);
-    println!("Depth * Horizontal: {}", depth * horizontal);
+/// The trajectories of both models, or the first command one of them cannot follow
+fn record_both(commands: &[Command], max_depth: Option<i64>) -> Result<[(&'static str, Trajectory); 2], NavigationError> {
+    let simple = Trajectory::record(&Simple, commands, max_depth)?;
+    let aim = Trajectory::record(&Aim, commands, max_depth)?;
+    Ok([("simple", simple), ("aim", aim)])
 }
 
-fn part2() {
-    let content = include_str!("input.txt");
-    let mut depth: i32 = 0;
-    let mut horizontal: i32 = 0;
-    let mut aim: i32 = 0;
+/// `--csv <simple|aim> [file|-]` prints the state after every command following one model.
+/// `--svg [file|-]` prints the depth profiles of both models as an SVG image.
+/// `--check [file|-]` prints where both models end up.
+/// All of them take `--max-depth <n>` and fail at the first command that leaves the allowed depths.
+/// Without a mode both parts multiply the final depth and horizontal position of the course
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mode = match args.first().map(String::as_str) {
+        Some(mode @ ("--csv" | "--svg" | "--check")) => mode.to_string(),
+        _ => {
+            aoc_common::solution::run_main::<Day02>();
+            return;
+        }
+    };
 
-    for l in content.lines() {
-        let (direction, increment) = l.split_once(' ').unwrap();
-        match direction {
-            "forward" => {
-                let i = increment.parse::<i32>().expect("Parsing error");
-                horizontal += i;
-                depth += aim * i;
-            },
-            "up" => {aim -= increment.parse::<i32>().expect("Parsing error")},
-            "down" => {aim += increment.parse::<i32>().expect("Parsing error")},
-            &_ => {panic!("This token was not expected!")}
+    let mut max_depth = None;
+    let mut operands = Vec::new();
+    let mut args = args.into_iter().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--max-depth" {
+            match args.next().and_then(|v| v.parse::<i64>().ok()).filter(|&v| v >= 0) {
+                Some(value) => max_depth = Some(value),
+                None => {
+                    eprintln!("--max-depth expects a depth of at least 0");
+                    exit(2);
+                }
+            }
+        } else {
+            operands.push(arg);
         }
     }
+    let model = if mode == "--csv" { operands.first().cloned() } else { None };
+    let source = operands.get(model.is_some() as usize)
+        .map_or(InputSource::Default, |arg| InputSource::from_arg(arg));
+    let commands = parse_main::<Day02>(&source);
 
-    println!("Depth: {}", depth);
-    println!("Horizontal: {}", horizontal);
-    println!("Aim: {}", aim);
-    println!("Depth * Horizontal: {}", depth * horizontal);
-}
-
-fn main() {
-    println!("Part1: ");
-    part1();
-
-    println!("\n\nPart2: ");
-    part2();
+    let result = match (mode.as_str(), model.as_deref()) {
+        ("--csv", Some("simple")) => Trajectory::record(&Simple, &commands, max_depth).map(|t| t.to_csv()),
+        ("--csv", Some("aim")) => Trajectory::record(&Aim, &commands, max_depth).map(|t| t.to_csv()),
+        ("--csv", _) => {
+            eprintln!("--csv expects the model 'simple' or 'aim'");
+            exit(2);
+        }
+        ("--svg", _) => record_both(&commands, max_depth)
+            .map(|[(a, simple), (b, aim)]| depth_profile(&[(a, &simple), (b, &aim)])),
+        _ => record_both(&commands, max_depth).map(|trajectories| {
+            trajectories.iter()
+                .map(|(name, t)| format!("{}: {}\n", name, t.end()))
+                .collect()
+        }),
+    };
+    match result {
+        Ok(output) => print!("{}", output),
+        Err(error) => {
+            eprintln!("Cannot navigate, {}", error);
+            exit(1);
+        }
+    }
 }
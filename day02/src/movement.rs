@@ -0,0 +1,219 @@
+//! The interpretations of the commands. A new one only has to implement `Movement`,
+//! checkpoints and the depth limits are handled the same way for every model
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::command::{Command, Move};
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "horizontal {}, depth {}, aim {}", self.horizontal, self.depth, self.aim)
+    }
+}
+
+/// How a move changes the position of the submarine
+pub trait Movement {
+    fn apply(&self, position: &mut Position, step: Move);
+}
+
+/// `down` and `up` change the depth directly, as first assumed in part 1
+pub struct Simple;
+
+impl Movement for Simple {
+    fn apply(&self, position: &mut Position, step: Move) {
+        match step {
+            Move::Forward(amount) => position.horizontal += amount as i64,
+            Move::Back(amount) => position.horizontal -= amount as i64,
+            Move::Down(amount) => position.depth += amount as i64,
+            Move::Up(amount) => position.depth -= amount as i64,
+            Move::SetAim(aim) => position.aim = aim as i64,
+        }
+    }
+}
+
+/// `down` and `up` change the aim, moving forward dives along it. The model of part 2
+pub struct Aim;
+
+impl Movement for Aim {
+    fn apply(&self, position: &mut Position, step: Move) {
+        match step {
+            Move::Forward(amount) => {
+                position.horizontal += amount as i64;
+                position.depth += position.aim * amount as i64;
+            }
+            Move::Back(amount) => {
+                position.horizontal -= amount as i64;
+                position.depth -= position.aim * amount as i64;
+            }
+            Move::Down(amount) => position.aim += amount as i64,
+            Move::Up(amount) => position.aim -= amount as i64,
+            Move::SetAim(aim) => position.aim = aim as i64,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Violation {
+    AboveSurface,
+    BelowMaxDepth(i64),
+    UnknownCheckpoint,
+}
+
+/// A command that cannot be followed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NavigationError {
+    /// Number of the command, starting at 1
+    pub step: usize,
+    pub command: Command,
+    /// The depth the command would have taken the submarine to
+    pub depth: i64,
+    pub violation: Violation,
+}
+
+impl Display for NavigationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "command {} '{}' ", self.step, self.command)?;
+        match self.violation {
+            Violation::AboveSurface => write!(f, "takes the submarine above the surface to depth {}", self.depth),
+            Violation::BelowMaxDepth(max) => write!(f, "takes the submarine to depth {}, below the maximum of {}", self.depth, max),
+            Violation::UnknownCheckpoint => write!(f, "returns to a checkpoint that has not been set"),
+        }
+    }
+}
+
+impl Error for NavigationError {}
+
+/// Follows the commands one at a time, yielding the position after each.
+/// Stops after the first command that cannot be followed
+pub struct Course<'a, M> {
+    model: &'a M,
+    commands: std::iter::Enumerate<std::slice::Iter<'a, Command>>,
+    max_depth: Option<i64>,
+    position: Position,
+    checkpoints: HashMap<&'a str, Position>,
+}
+
+impl<'a, M: Movement> Course<'a, M> {
+    /// Starts at the surface. Without a `max_depth` the submarine may dive arbitrarily deep
+    pub fn new(model: &'a M, commands: &'a [Command], max_depth: Option<i64>) -> Course<'a, M> {
+        Course {
+            model,
+            commands: commands.iter().enumerate(),
+            max_depth,
+            position: Position::default(),
+            checkpoints: HashMap::new(),
+        }
+    }
+
+    /// The position after the command, or what is wrong with it and the depth it leads to
+    fn follow(&mut self, command: &'a Command) -> Result<Position, (Violation, i64)> {
+        let next = match command {
+            Command::Move(step) => {
+                let mut next = self.position;
+                self.model.apply(&mut next, *step);
+                next
+            }
+            Command::Checkpoint(name) => {
+                self.checkpoints.insert(name, self.position);
+                self.position
+            }
+            Command::ReturnTo(name) => *self.checkpoints.get(name.as_str())
+                .ok_or((Violation::UnknownCheckpoint, self.position.depth))?,
+        };
+        match self.max_depth {
+            _ if next.depth < 0 => Err((Violation::AboveSurface, next.depth)),
+            Some(max) if next.depth > max => Err((Violation::BelowMaxDepth(max), next.depth)),
+            _ => Ok(next),
+        }
+    }
+}
+
+impl<M: Movement> Iterator for Course<'_, M> {
+    type Item = Result<Position, NavigationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, command) = self.commands.next()?;
+        Some(match self.follow(command) {
+            Ok(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            Err((violation, depth)) => {
+                // Nothing after a failed command is followed
+                self.commands.by_ref().for_each(drop);
+                Err(NavigationError { step: index + 1, command: command.clone(), depth, violation })
+            }
+        })
+    }
+}
+
+/// Follows all commands from the surface
+pub fn navigate<M: Movement>(model: &M, commands: &[Command], max_depth: Option<i64>) -> Result<Position, NavigationError> {
+    Course::new(model, commands, max_depth).try_fold(Position::default(), |_, position| position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COURSE: [Command; 6] = [Command::Move(Move::Forward(5)), Command::Move(Move::Down(5)),
+        Command::Move(Move::Forward(8)), Command::Move(Move::Up(3)), Command::Move(Move::Down(8)),
+        Command::Move(Move::Forward(2))];
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(navigate(&Simple, &COURSE, None), Ok(Position { horizontal: 15, depth: 10, aim: 0 }));
+    }
+
+    #[test]
+    fn test_aim() {
+        assert_eq!(navigate(&Aim, &COURSE, None), Ok(Position { horizontal: 15, depth: 60, aim: 10 }));
+    }
+
+    /// Models only need to implement `apply` to be used with `navigate`
+    #[test]
+    fn test_custom_model() {
+        struct Steep;
+        impl Movement for Steep {
+            fn apply(&self, position: &mut Position, step: Move) {
+                Simple.apply(position, match step {
+                    Move::Down(amount) => Move::Down(amount * 2),
+                    other => other,
+                });
+            }
+        }
+        assert_eq!(navigate(&Steep, &COURSE, None).unwrap().depth, 23);
+    }
+
+    #[test]
+    fn test_extended_commands() {
+        let commands = [Command::Move(Move::SetAim(2)), Command::Move(Move::Forward(10)),
+            Command::Checkpoint("reef".to_string()), Command::Move(Move::Back(4)),
+            Command::Move(Move::Forward(20)), Command::ReturnTo("reef".to_string())];
+        assert_eq!(navigate(&Aim, &commands, None), Ok(Position { horizontal: 10, depth: 20, aim: 2 }));
+        assert_eq!(navigate(&Simple, &commands[..4], None), Ok(Position { horizontal: 6, depth: 0, aim: 2 }));
+    }
+
+    #[test]
+    fn test_limits() {
+        assert!(navigate(&Simple, &COURSE, Some(10)).is_ok());
+        let error = navigate(&Simple, &COURSE, Some(8)).unwrap_err();
+        assert_eq!((error.step, error.depth, error.violation), (5, 10, Violation::BelowMaxDepth(8)));
+        assert_eq!(error.to_string(), "command 5 'down 8' takes the submarine to depth 10, below the maximum of 8");
+
+        let error = navigate(&Simple, &[Command::Move(Move::Up(1))], None).unwrap_err();
+        assert_eq!(error.to_string(), "command 1 'up 1' takes the submarine above the surface to depth -1");
+
+        let error = navigate(&Aim, &[Command::ReturnTo("home".to_string())], None).unwrap_err();
+        assert_eq!(error.violation, Violation::UnknownCheckpoint);
+    }
+}
@@ -0,0 +1,109 @@
+//! Records every state of the submarine along the course, to be exported as CSV or drawn as a depth profile
+
+use std::fmt::Write;
+
+use crate::command::Command;
+use crate::movement::{Course, Movement, NavigationError, Position};
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 400.0;
+const MARGIN: f64 = 50.0;
+const COLORS: [&str; 4] = ["steelblue", "crimson", "seagreen", "darkorange"];
+
+/// The position after each command, following one movement model
+#[derive(Debug, Clone)]
+pub struct Trajectory {
+    pub steps: Vec<(Command, Position)>,
+}
+
+impl Trajectory {
+    /// Follows the commands, failing at the first one that breaks the depth limits
+    pub fn record<M: Movement>(model: &M, commands: &[Command], max_depth: Option<i64>) -> Result<Trajectory, NavigationError> {
+        let positions = Course::new(model, commands, max_depth).collect::<Result<Vec<_>, _>>()?;
+        Ok(Trajectory { steps: commands.iter().cloned().zip(positions).collect() })
+    }
+
+    /// The final position, the surface if there were no commands
+    pub fn end(&self) -> Position {
+        self.steps.last().map(|&(_, position)| position).unwrap_or_default()
+    }
+
+    /// One row per command with the state after it
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,command,horizontal,depth,aim\n");
+        for (step, (command, position)) in self.steps.iter().enumerate() {
+            writeln!(csv, "{},{},{},{},{}", step + 1, command, position.horizontal, position.depth, position.aim).unwrap();
+        }
+        csv
+    }
+
+    /// The depth over the horizontal position, starting at the surface
+    fn points(&self) -> impl Iterator<Item = (i64, i64)> + '_ {
+        std::iter::once((0, 0)).chain(self.steps.iter().map(|(_, p)| (p.horizontal, p.depth)))
+    }
+}
+
+/// Draws the depth profiles of the named trajectories into one chart with shared axes.
+/// Depth grows downwards, the surface is the top line
+pub fn depth_profile(trajectories: &[(&str, &Trajectory)]) -> String {
+    let points = || trajectories.iter().flat_map(|(_, t)| t.points());
+    let (min_x, max_x) = points().fold((0, 1), |(lo, hi), (x, _)| (lo.min(x), hi.max(x)));
+    let (min_y, max_y) = points().fold((0, 1), |(lo, hi), (_, y)| (lo.min(y), hi.max(y)));
+    let scale_x = (WIDTH - 2.0 * MARGIN) / (max_x - min_x) as f64;
+    let scale_y = (HEIGHT - 2.0 * MARGIN) / (max_y - min_y) as f64;
+    let x = |value: i64| MARGIN + (value - min_x) as f64 * scale_x;
+    let y = |value: i64| MARGIN + (value - min_y) as f64 * scale_y;
+
+    let mut svg = String::new();
+    writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="sans-serif" font-size="12">"#,
+             w = WIDTH, h = HEIGHT).unwrap();
+    writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+    writeln!(svg, r#"<line x1="{}" y1="{y}" x2="{}" y2="{y}" stroke="gray" stroke-dasharray="4"/>"#, x(min_x), x(max_x), y = y(0)).unwrap();
+    writeln!(svg, r#"<text x="{}" y="{}">horizontal {}</text>"#, x(max_x) - 100.0, HEIGHT - 15.0, max_x).unwrap();
+    writeln!(svg, r#"<text x="5" y="{}">depth {}</text>"#, y(max_y) + 15.0, max_y).unwrap();
+    for (i, (name, trajectory)) in trajectories.iter().enumerate() {
+        let color = COLORS[i % COLORS.len()];
+        let line = trajectory.points()
+            .map(|(px, py)| format!("{:.1},{:.1}", x(px), y(py)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        writeln!(svg, r#"<polyline fill="none" stroke="{}" stroke-width="1.5" points="{}"/>"#, color, line).unwrap();
+        writeln!(svg, r#"<text x="{}" y="{}" fill="{}">{}</text>"#, MARGIN, 20.0 + 15.0 * i as f64, color, name).unwrap();
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::Move;
+    use crate::movement::{Aim, Simple};
+
+    const COURSE: [Command; 3] = [Command::Move(Move::Forward(5)), Command::Move(Move::Down(5)), Command::Move(Move::Forward(8))];
+
+    #[test]
+    fn test_record() {
+        let trajectory = Trajectory::record(&Aim, &COURSE, None).unwrap();
+        assert_eq!(trajectory.steps.len(), 3);
+        assert_eq!(trajectory.steps[1].1, Position { horizontal: 5, depth: 0, aim: 5 });
+        assert_eq!(trajectory.end(), Position { horizontal: 13, depth: 40, aim: 5 });
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = Trajectory::record(&Simple, &COURSE, None).unwrap().to_csv();
+        assert_eq!(csv, "step,command,horizontal,depth,aim\n1,forward 5,5,0,0\n2,down 5,5,5,0\n3,forward 8,13,5,0\n");
+    }
+
+    #[test]
+    fn test_depth_profile() {
+        let simple = Trajectory::record(&Simple, &COURSE, None).unwrap();
+        let aim = Trajectory::record(&Aim, &COURSE, None).unwrap();
+        let svg = depth_profile(&[("simple", &simple), ("aim", &aim)]);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+        // The deepest point of the aim model lies at the bottom right corner
+        assert!(svg.contains("750.0,350.0"));
+    }
+}
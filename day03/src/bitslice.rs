@@ -0,0 +1,149 @@
+//! Counts the 1s of every bit position with bit-sliced adders. A 64 bit word holds one bit of
+//! 64 columns, so a single AND/XOR adds to 64 counters at once. Eight lines are first summed with
+//! carry-save adders and only their 4 bit total is added to the counters
+
+use crate::report::Bits;
+
+/// The counters have this many bits, so they are emptied before they reach `2^PLANES`
+const PLANES: usize = 16;
+const LIMIT: usize = (1 << PLANES) - 1;
+
+/// Sum and carry of three bits in every position
+fn full_add(a: u64, b: u64, c: u64) -> (u64, u64) {
+    let partial = a ^ b;
+    (partial ^ c, (a & b) | (c & partial))
+}
+
+/// 64 counters stored vertically, plane `j` holds bit `j` of every counter
+struct VerticalCounter {
+    planes: [u64; PLANES],
+    pending: usize,
+}
+
+impl VerticalCounter {
+    fn new() -> VerticalCounter {
+        VerticalCounter { planes: [0; PLANES], pending: 0 }
+    }
+
+    /// Adds `2^plane` to every counter whose bit is set in `value`
+    fn add(&mut self, mut value: u64, plane: usize) {
+        for counter in &mut self.planes[plane..] {
+            if value == 0 {
+                break;
+            }
+            let carry = *counter & value;
+            *counter ^= value;
+            value = carry;
+        }
+    }
+
+    /// Adds the 1s of eight words
+    fn add_eight(&mut self, x: &[u64; 8]) {
+        let (sum_a, carry_a) = full_add(x[0], x[1], x[2]);
+        let (sum_b, carry_b) = full_add(x[3], x[4], x[5]);
+        let (sum_c, carry_c) = full_add(sum_a, sum_b, x[6]);
+        let (ones, carry_d) = (sum_c ^ x[7], sum_c & x[7]);
+        let (sum_e, carry_e) = full_add(carry_a, carry_b, carry_c);
+        let (twos, carry_f) = (sum_e ^ carry_d, sum_e & carry_d);
+        let (fours, eights) = (carry_e ^ carry_f, carry_e & carry_f);
+        self.add(ones, 0);
+        self.add(twos, 1);
+        self.add(fours, 2);
+        self.add(eights, 3);
+        self.pending += 8;
+    }
+
+    fn add_one(&mut self, x: u64) {
+        self.add(x, 0);
+        self.pending += 1;
+    }
+
+    /// Moves the counts to `counts` once another eight lines might overflow the counters
+    fn flush_if_full(&mut self, counts: &mut [usize]) {
+        if self.pending > LIMIT - 8 {
+            self.flush(counts);
+        }
+    }
+
+    fn flush(&mut self, counts: &mut [usize]) {
+        for (j, plane) in self.planes.iter_mut().enumerate() {
+            for (i, count) in counts.iter_mut().enumerate() {
+                *count += (((*plane >> i) & 1) as usize) << j;
+            }
+            *plane = 0;
+        }
+        self.pending = 0;
+    }
+}
+
+/// How many numbers have a 1 at every bit position below `width`, bit 0 first.
+/// Reports of fewer than eight lines never fill the adders and are counted one bit at a time
+pub fn count_ones<B: Bits>(numbers: &[B], width: usize) -> Vec<usize> {
+    if numbers.len() < 8 {
+        return count_ones_scalar(numbers, width);
+    }
+    let mut counts = vec![0; width.div_ceil(64) * 64];
+    for (index, counts) in counts.chunks_mut(64).enumerate() {
+        let mut counter = VerticalCounter::new();
+        let chunks = numbers.chunks_exact(8);
+        let rest = chunks.remainder();
+        for chunk in chunks {
+            let mut words = [0u64; 8];
+            for (word, number) in words.iter_mut().zip(chunk) {
+                *word = number.word(index);
+            }
+            counter.add_eight(&words);
+            counter.flush_if_full(counts);
+        }
+        for number in rest {
+            counter.add_one(number.word(index));
+        }
+        counter.flush(counts);
+    }
+    counts.truncate(width);
+    counts
+}
+
+/// The same counts one bit at a time
+pub fn count_ones_scalar<B: Bits>(numbers: &[B], width: usize) -> Vec<usize> {
+    let mut counts = vec![0; width];
+    for number in numbers {
+        for (bit, count) in counts.iter_mut().enumerate() {
+            *count += number.bit(bit) as usize;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::GeneratorConfig;
+    use rand::Rng;
+
+    use super::*;
+    use crate::report::BitSet;
+
+    #[test]
+    fn test_same_as_scalar() {
+        let mut rng = GeneratorConfig::new(1, 3).rng();
+        // Enough lines to empty the counters in between, and a few left over after the last eight
+        let numbers: Vec<u64> = (0..2 * LIMIT + 5).map(|_| rng.gen::<u64>() & rng.gen::<u64>()).collect();
+        assert_eq!(count_ones(&numbers, 64), count_ones_scalar(&numbers, 64));
+        assert_eq!(count_ones(&numbers[..13], 12), count_ones_scalar(&numbers[..13], 12));
+        assert_eq!(count_ones(&numbers[..0], 5), vec![0; 5]);
+    }
+
+    #[test]
+    fn test_wide_numbers() {
+        let mut rng = GeneratorConfig::new(1, 4).rng();
+        let numbers: Vec<BitSet> = (0..1000).map(|_| {
+            let mut number = BitSet::zeros(150);
+            (0..150).filter(|_| rng.gen_bool(0.3)).for_each(|bit| number.set(bit));
+            number
+        }).collect();
+        assert_eq!(count_ones(&numbers, 150), count_ones_scalar(&numbers, 150));
+
+        let numbers: Vec<u128> = (0..1000).map(|_| rng.gen::<u128>() >> 28).collect();
+        assert_eq!(count_ones(&numbers, 100), count_ones_scalar(&numbers, 100));
+    }
+}
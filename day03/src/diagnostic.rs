@@ -0,0 +1,98 @@
+//! What the diagnostic report tells about the submarine: the column statistics,
+//! the gamma and epsilon rates of part 1 and the oxygen and CO2 ratings of part 2
+
+use aoc_common::{progress, Answer};
+
+use crate::bitslice::count_ones;
+use crate::report::{product, BigNumber, Bits, Report};
+use crate::trie::{least_common, most_common, Trie};
+
+/// How many numbers have a 1 in each column, the leftmost column first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnCounts {
+    pub ones: Vec<usize>,
+    pub lines: usize,
+}
+
+impl ColumnCounts {
+    pub fn new<B: Bits>(report: &Report<B>) -> ColumnCounts {
+        // Counted per bit, which runs from the right
+        let mut ones = count_ones(&report.numbers, report.width);
+        ones.reverse();
+        ColumnCounts { ones, lines: report.numbers.len() }
+    }
+
+    pub fn zeros(&self, column: usize) -> usize {
+        self.lines - self.ones[column]
+    }
+
+    /// Whether most numbers have a 1 in the column
+    pub fn mostly_ones(&self, column: usize) -> bool {
+        self.ones[column] > self.lines / 2
+    }
+
+    /// Gamma has the most common bit of every column, epsilon the least common one
+    pub fn gamma_epsilon<B: Bits>(&self) -> (B, B) {
+        let width = self.ones.len();
+        let mut gamma = B::zeros(width);
+        let mut epsilon = B::zeros(width);
+        for column in 0..width {
+            if self.mostly_ones(column) {
+                gamma.set(width - 1 - column);
+            } else {
+                epsilon.set(width - 1 - column);
+            }
+        }
+        (gamma, epsilon)
+    }
+}
+
+/// The oxygen generator and CO2 scrubber ratings
+pub fn ratings<B: Bits>(report: &Report<B>) -> (B, B) {
+    let trie = Trie::new(report);
+    let oxygen = trie.rating(most_common).expect("A report has at least one number");
+    let co2 = trie.rating(least_common).expect("A report has at least one number");
+    (oxygen, co2)
+}
+
+pub fn power_consumption<B: Bits>(report: &Report<B>) -> Answer {
+    let counts = ColumnCounts::new(report);
+    progress!("{:?}", counts.ones);
+    progress!("Total linecount: {}", counts.lines);
+
+    let (gamma, epsilon) = counts.gamma_epsilon::<B>();
+    progress!("Resulting (decimal) gamma: {}", BigNumber::of(&gamma));
+    progress!("Resulting (decimal) epsilon: {}", BigNumber::of(&epsilon));
+    product(&gamma, &epsilon)
+}
+
+pub fn life_support_rating<B: Bits>(report: &Report<B>) -> Answer {
+    let (oxygen, co2) = ratings(report);
+    progress!("Oxygen: {}", BigNumber::of(&oxygen));
+    progress!("CO2: {}", BigNumber::of(&co2));
+    product(&oxygen, &co2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_counts() {
+        let counts = ColumnCounts::new(&Report::sample());
+        assert_eq!(counts.ones, vec![7, 5, 8, 7, 5]);
+        assert_eq!(counts.zeros(1), 7);
+        assert_eq!(counts.gamma_epsilon::<u64>(), (22, 9));
+    }
+
+    #[test]
+    fn test_ratings() {
+        assert_eq!(ratings(&Report::sample()), (23, 10));
+    }
+
+    #[test]
+    fn test_answers() {
+        assert_eq!(power_consumption(&Report::sample()), Answer::Number(198));
+        assert_eq!(life_support_rating(&Report::sample()), Answer::Number(230));
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use rand::seq::index::sample;
+
+use crate::Day03;
+
+/// Width of the numbers in the real input
+const WIDTH: usize = 12;
+
+/// 1000 distinct diagnostic numbers per scale. The ratings are only well defined for
+/// distinct numbers, and with all possible numbers every column would have as many 0s as 1s,
+/// so there are never more than half of the numbers the bit width allows.
+/// Larger scales use wider numbers than the 12 bits of the real input to stay below that
+impl Generate for Day03 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        let count = config.scaled(1000);
+        let width = (WIDTH..).find(|&width| count <= 1 << (width - 1)).unwrap();
+        for number in sample(&mut rng, 1 << width, count) {
+            writeln!(input, "{:0width$b}", number, width = width).unwrap();
+        }
+        input
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::{Answer, NoAnswer, ParseError, Solution};
+
+use crate::report::Diagnostic;
+
+pub mod bitslice;
+pub mod diagnostic;
+mod generator;
+pub mod report;
+pub mod trie;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const DEFAULT_INPUT: &'static str = "day03/src/input.txt";
+
+    type Input = Diagnostic;
+    type Answer1 = Answer;
+    type Answer2 = Answer;
+
+    /// Checks that every line is a binary number of the same width
+    fn parse(contents: &str) -> Result<Diagnostic, ParseError> {
+        Diagnostic::parse(contents)
+    }
+
+    fn part1(diagnostic: &Diagnostic) -> Result<Answer, NoAnswer> {
+        Ok(with_report!(diagnostic, report => diagnostic::power_consumption(report)))
+    }
+
+    fn part2(diagnostic: &Diagnostic) -> Result<Answer, NoAnswer> {
+        Ok(with_report!(diagnostic, report => diagnostic::life_support_rating(report)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day03;
+        sample: "test.txt" => 198, 230;
+    }
+}
@@ -0,0 +1,30 @@
+use aoc_common::solution::parse_main;
+use aoc_common::InputSource;
+use day03::diagnostic::ColumnCounts;
+use day03::report::{BigNumber, Bits, Report};
+use day03::{with_report, Day03};
+
+/// Prints how often each column holds a 0 and a 1, and the rates they result in
+fn print_columns<B: Bits>(report: &Report<B>) {
+    let counts = ColumnCounts::new(report);
+    println!("column  zeros   ones");
+    for column in 0..report.width {
+        println!("{:>6} {:>6} {:>6}", column + 1, counts.zeros(column), counts.ones[column]);
+    }
+    let (gamma, epsilon) = counts.gamma_epsilon::<B>();
+    println!("Gamma: {}", BigNumber::of(&gamma));
+    println!("Epsilon: {}", BigNumber::of(&epsilon));
+}
+
+/// `--columns [file|-]` prints the statistics of every column of the report.
+/// Otherwise the power consumption and the life support rating are solved from the report
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_none_or(|arg| arg != "--columns") {
+        aoc_common::solution::run_main::<Day03>();
+        return;
+    }
+    let source = args.get(1).map_or(InputSource::Default, |arg| InputSource::from_arg(arg));
+    let diagnostic = parse_main::<Day03>(&source);
+    with_report!(&diagnostic, report => print_columns(report));
+}
@@ -0,0 +1,293 @@
+//! The diagnostic numbers, stored in the smallest type that fits the width of the report
+
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+use aoc_common::{Answer, ParseError, Span};
+
+/// A fixed width binary number. Bit 0 is the rightmost digit
+pub trait Bits: Clone {
+    fn zeros(width: usize) -> Self;
+    fn bit(&self, index: usize) -> bool;
+    fn set(&mut self, index: usize);
+    /// The value as 64 bit words, least significant first
+    fn words(&self) -> Vec<u64>;
+    /// One of the 64 bit words, `index` 0 holds bits 0 to 63
+    fn word(&self, index: usize) -> u64;
+}
+
+impl Bits for u64 {
+    fn zeros(_: usize) -> Self {
+        0
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        (self >> index) & 1 == 1
+    }
+
+    fn set(&mut self, index: usize) {
+        *self |= 1 << index;
+    }
+
+    fn words(&self) -> Vec<u64> {
+        vec![*self]
+    }
+
+    fn word(&self, _: usize) -> u64 {
+        *self
+    }
+}
+
+impl Bits for u128 {
+    fn zeros(_: usize) -> Self {
+        0
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        (self >> index) & 1 == 1
+    }
+
+    fn set(&mut self, index: usize) {
+        *self |= 1 << index;
+    }
+
+    fn words(&self) -> Vec<u64> {
+        vec![*self as u64, (self >> 64) as u64]
+    }
+
+    fn word(&self, index: usize) -> u64 {
+        (self >> (64 * index)) as u64
+    }
+}
+
+/// Numbers wider than 128 bits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl Bits for BitSet {
+    fn zeros(width: usize) -> Self {
+        BitSet { words: vec![0; width.div_ceil(64)] }
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        (self.words[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    fn words(&self) -> Vec<u64> {
+        self.words.clone()
+    }
+
+    fn word(&self, index: usize) -> u64 {
+        self.words[index]
+    }
+}
+
+/// All numbers of a report, every one `width` digits long
+#[derive(Debug, Clone)]
+pub struct Report<B> {
+    pub width: usize,
+    pub numbers: Vec<B>,
+}
+
+impl<B: Bits> Report<B> {
+    fn parse(lines: &[Span], width: usize) -> Report<B> {
+        let numbers = lines.iter().map(|line| {
+            let mut number = B::zeros(width);
+            for (column, (c, _)) in line.chars().enumerate() {
+                if c == '1' {
+                    number.set(width - 1 - column);
+                }
+            }
+            number
+        }).collect();
+        Report { width, numbers }
+    }
+}
+
+#[cfg(test)]
+impl Report<u64> {
+    /// The report of the puzzle description, shared by the tests of all modules
+    pub(crate) fn sample() -> Report<u64> {
+        match Diagnostic::parse(include_str!("test.txt")).unwrap() {
+            Diagnostic::Narrow(report) => report,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A report in the type matching its width
+#[derive(Debug, Clone)]
+pub enum Diagnostic {
+    Narrow(Report<u64>),
+    Wide(Report<u128>),
+    Huge(Report<BitSet>),
+}
+
+/// Runs the same generic code on whichever report the diagnostic holds
+#[macro_export]
+macro_rules! with_report {
+    ($diagnostic:expr, $report:ident => $body:expr) => {
+        match $diagnostic {
+            $crate::report::Diagnostic::Narrow($report) => $body,
+            $crate::report::Diagnostic::Wide($report) => $body,
+            $crate::report::Diagnostic::Huge($report) => $body,
+        }
+    };
+}
+
+impl Diagnostic {
+    /// The width is taken from the first line, all other lines must have the same one
+    pub fn parse(contents: &str) -> Result<Diagnostic, ParseError> {
+        let lines: Vec<Span> = Span::lines(contents).collect();
+        let width = match lines.first() {
+            Some(first) if first.is_empty() => return Err(first.error("a binary digit")),
+            Some(first) => first.as_str().chars().count(),
+            None => return Err(ParseError::at_end_of(contents, "a binary number")),
+        };
+        for line in &lines {
+            let mut digits = 0;
+            for (c, span) in line.chars() {
+                if digits == width {
+                    return Err(span.error(format!("the end of the line after {} digits like in line 1", width)));
+                }
+                if c != '0' && c != '1' {
+                    return Err(span.error("a binary digit"));
+                }
+                digits += 1;
+            }
+            if digits < width {
+                return Err(line.end().error(format!("{} binary digits like in line 1", width)));
+            }
+        }
+
+        Ok(match width {
+            0..=64 => Diagnostic::Narrow(Report::parse(&lines, width)),
+            65..=128 => Diagnostic::Wide(Report::parse(&lines, width)),
+            _ => Diagnostic::Huge(Report::parse(&lines, width)),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        with_report!(self, report => report.width)
+    }
+}
+
+/// An unsigned integer of any size, as 32 bit limbs with the least significant first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigNumber {
+    limbs: Vec<u32>,
+}
+
+impl BigNumber {
+    pub fn of<B: Bits>(number: &B) -> BigNumber {
+        let limbs = number.words().iter().flat_map(|&w| [w as u32, (w >> 32) as u32]).collect();
+        BigNumber { limbs }.trimmed()
+    }
+
+    fn trimmed(mut self) -> BigNumber {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+
+    pub fn multiply(&self, other: &BigNumber) -> BigNumber {
+        let mut limbs = vec![0u32; self.limbs.len() + other.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in other.limbs.iter().enumerate() {
+                let value = limbs[i + j] as u64 + a as u64 * b as u64 + carry;
+                limbs[i + j] = value as u32;
+                carry = value >> 32;
+            }
+            limbs[i + other.limbs.len()] = carry as u32;
+        }
+        BigNumber { limbs }.trimmed()
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [low] => Some(low as i64),
+            [low, high] => i64::try_from((high as u64) << 32 | low as u64).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl Display for BigNumber {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Splits off nine decimal digits at a time
+        const CHUNK: u64 = 1_000_000_000;
+        let mut limbs = self.limbs.clone();
+        let mut chunks = Vec::new();
+        while !limbs.is_empty() {
+            let mut remainder = 0u64;
+            for limb in limbs.iter_mut().rev() {
+                let value = remainder << 32 | *limb as u64;
+                *limb = (value / CHUNK) as u32;
+                remainder = value % CHUNK;
+            }
+            chunks.push(remainder);
+            while limbs.last() == Some(&0) {
+                limbs.pop();
+            }
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{}", first)?;
+                rest.iter().rev().try_for_each(|chunk| write!(f, "{:09}", chunk))
+            }
+        }
+    }
+}
+
+/// The product of two ratings, as text if it does not fit into an `Answer::Number`
+pub fn product<B: Bits>(a: &B, b: &B) -> Answer {
+    let product = BigNumber::of(a).multiply(&BigNumber::of(b));
+    match product.to_i64() {
+        Some(number) => Answer::Number(number),
+        None => Answer::Text(product.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_width() {
+        assert!(matches!(Diagnostic::parse("00100\n11110\n"), Ok(Diagnostic::Narrow(Report { width: 5, .. }))));
+        assert!(matches!(Diagnostic::parse(&"1".repeat(100)), Ok(Diagnostic::Wide(Report { width: 100, .. }))));
+        assert!(matches!(Diagnostic::parse(&"10".repeat(100)), Ok(Diagnostic::Huge(Report { width: 200, .. }))));
+
+        let error = Diagnostic::parse("00100\n1111\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 5, "5 binary digits like in line 1"));
+        let error = Diagnostic::parse("00100\n111100\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+    }
+
+    #[test]
+    fn test_bit_set() {
+        let mut bits = BitSet::zeros(130);
+        bits.set(129);
+        bits.set(3);
+        assert!(bits.bit(129) && bits.bit(3) && !bits.bit(64));
+        assert_eq!(bits.words(), vec![8, 0, 2]);
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product(&22u64, &9u64), Answer::Number(198));
+        assert_eq!(product(&u64::MAX, &u64::MAX), Answer::Text("340282366920938463426481119284349108225".to_string()));
+        assert_eq!(BigNumber::of(&u128::MAX).to_string(), "340282366920938463463374607431768211455");
+        assert_eq!(BigNumber::of(&0u64).to_string(), "0");
+    }
+}
@@ -0,0 +1,121 @@
+//! A binary trie over the numbers of a report. Every node counts the numbers sharing its prefix,
+//! so the ratings are found with one walk from the most significant bit down
+
+use crate::report::{Bits, Report};
+
+/// Marks a missing child. The root is never a child, so its index is free
+const NONE: u32 = 0;
+
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    count: usize,
+    children: [u32; 2],
+}
+
+/// The numbers left at one step of a walk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    /// The bit that is looked at, counted from the right
+    pub bit: usize,
+    pub zeros: usize,
+    pub ones: usize,
+}
+
+/// Keeps the numbers with the more common bit, those with a 1 on a tie. Finds the oxygen generator rating
+pub fn most_common(counts: Counts) -> bool {
+    counts.ones >= counts.zeros
+}
+
+/// Keeps the numbers with the less common bit, those with a 0 on a tie. Finds the CO2 scrubber rating
+pub fn least_common(counts: Counts) -> bool {
+    counts.ones < counts.zeros
+}
+
+#[derive(Debug, Clone)]
+pub struct Trie {
+    width: usize,
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    pub fn new<B: Bits>(report: &Report<B>) -> Trie {
+        let empty = Node { count: 0, children: [NONE; 2] };
+        let mut nodes = vec![empty];
+        for number in &report.numbers {
+            let mut node = 0;
+            nodes[node].count += 1;
+            for bit in (0..report.width).rev() {
+                let digit = number.bit(bit) as usize;
+                if nodes[node].children[digit] == NONE {
+                    nodes[node].children[digit] = nodes.len() as u32;
+                    nodes.push(empty);
+                }
+                node = nodes[node].children[digit] as usize;
+                nodes[node].count += 1;
+            }
+        }
+        Trie { width: report.width, nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn count(&self, child: u32) -> usize {
+        if child == NONE { 0 } else { self.nodes[child as usize].count }
+    }
+
+    /// Filters the numbers bit by bit, keeping those whose bit is the one `criterion` picks,
+    /// until a single number is left. If all numbers left share a bit there is nothing to pick
+    /// from, so `criterion` is only asked when there are both 0s and 1s.
+    /// `None` for an empty trie
+    pub fn rating<B: Bits>(&self, criterion: impl Fn(Counts) -> bool) -> Option<B> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut number = B::zeros(self.width);
+        let mut node = 0;
+        for bit in (0..self.width).rev() {
+            let [zero, one] = self.nodes[node].children;
+            let counts = Counts { bit, zeros: self.count(zero), ones: self.count(one) };
+            let digit = match counts {
+                Counts { zeros: 0, .. } => true,
+                Counts { ones: 0, .. } => false,
+                _ => criterion(counts),
+            };
+            if digit {
+                number.set(bit);
+            }
+            node = self.nodes[node].children[digit as usize] as usize;
+        }
+        Some(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratings() {
+        let trie = Trie::new(&Report::sample());
+        assert_eq!(trie.len(), 12);
+        assert_eq!(trie.rating::<u64>(most_common), Some(23));
+        assert_eq!(trie.rating::<u64>(least_common), Some(10));
+    }
+
+    #[test]
+    fn test_custom_criterion() {
+        let trie = Trie::new(&Report::sample());
+        // Always prefer a 1 where possible, which finds the largest number
+        assert_eq!(trie.rating::<u64>(|_| true), Some(0b11110));
+        assert_eq!(trie.rating::<u64>(|_| false), Some(0b00010));
+        // Follows the more common bit in the leftmost two columns only
+        let rating = trie.rating::<u64>(|counts| if counts.bit >= 3 { most_common(counts) } else { false });
+        assert_eq!(rating, Some(0b10000));
+    }
+}
@@ -0,0 +1,103 @@
+use std::fmt::{Display, Formatter};
+
+use ansi_term::Color::Red;
+
+use crate::mask::Mask;
+use crate::pattern::Pattern;
+
+/// Number of rows and columns of the boards of a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Shape {
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl Shape {
+    pub fn cells(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> usize {
+        row * self.columns + column
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}×{}", self.rows, self.columns)
+    }
+}
+
+/// The numbers of a board row by row, and which of them are marked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Board {
+    shape: Shape,
+    numbers: Vec<u8>,
+    marked: Mask,
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, &number) in self.numbers.iter().enumerate() {
+            if (i % self.shape.columns == 0) && i != 0 {
+                writeln!(f)?;
+            }
+            if number < 10 {
+                write!(f, " ")?;
+            }
+            if self.marked.contains(i) {
+                write!(f, "{}", Red.paint(number.to_string()))?;
+            } else {
+                write!(f, "{}", number)?;
+            }
+            write!(f, "  ")?;
+        }
+        Ok(())
+    }
+}
+
+impl Board {
+    pub fn new(shape: Shape, numbers: Vec<u8>) -> Board {
+        assert_eq!(numbers.len(), shape.cells(), "A {} board has {} numbers", shape, shape.cells());
+        Board { shape, numbers, marked: Mask::empty(shape.cells()) }
+    }
+
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    pub fn numbers(&self) -> &[u8] {
+        &self.numbers
+    }
+
+    pub fn marked(&self) -> &Mask {
+        &self.marked
+    }
+
+    /// Marks the number and returns its cell, `None` if the board does not have it
+    pub fn occupy_number(&mut self, number: u8) -> Option<usize> {
+        let cell = self.numbers.iter().position(|&n| n == number)?;
+        self.marked.insert(cell);
+        Some(cell)
+    }
+
+    /// The first of the patterns that is completely marked
+    pub fn winning_pattern<'a>(&self, patterns: &'a [Pattern]) -> Option<&'a Pattern> {
+        patterns.iter().find(|pattern| self.marked.covers(&pattern.mask))
+    }
+
+    pub fn has_won(&self, patterns: &[Pattern]) -> bool {
+        self.winning_pattern(patterns).is_some()
+    }
+
+    pub fn sum_marked_numbers(&self) -> i32 {
+        self.numbers.iter().enumerate()
+            .filter(|&(i, _)| self.marked.contains(i))
+            .map(|(_, &number)| number as i32)
+            .sum()
+    }
+
+    pub fn sum_unmarked_numbers(&self) -> i32 {
+        self.numbers.iter().map(|&i| i as i32).sum::<i32>() - self.sum_marked_numbers()
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use itertools::Itertools;
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+
+use crate::Day04;
+
+/// All numbers from 0 to 99 are drawn, so every board wins eventually. 100 boards per scale
+impl Generate for Day04 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut sequence = (0..100).collect_vec();
+        sequence.shuffle(&mut rng);
+        let mut input = sequence.iter().join(",");
+        input.push('\n');
+
+        for _ in 0..config.scaled(100) {
+            input.push('\n');
+            let numbers = sample(&mut rng, 100, 25).into_vec();
+            for row in numbers.chunks(5) {
+                writeln!(input, "{}", row.iter().map(|n| format!("{:>2}", n)).join(" ")).unwrap();
+            }
+        }
+        input
+    }
+}
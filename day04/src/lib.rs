@@ -0,0 +1,445 @@
+use std::fmt::{Display, Formatter};
+
+use aoc_common::{debug, progress, NoAnswer, ParseError, Solution, Span};
+use itertools::{Itertools, repeat_n};
+
+use crate::board::{Board, Shape};
+use crate::pattern::{Pattern, PatternKind, Rule};
+
+pub mod board;
+mod generator;
+pub mod mask;
+pub mod odds;
+pub mod pattern;
+pub mod replay;
+
+/// Stores information to retrieve all boards that include a certain number
+/// BoardLookupTable.iterateBoards(number) returns a slice of all boards
+/// with that given number so they can be udpated accordingly
+#[derive(Debug, Clone)]
+pub struct BoardLookupTable {
+    max_number_occurrence: usize,
+    board_table: Vec<u32>,
+}
+
+impl BoardLookupTable {
+    fn new(max_number: usize, max_number_occurrence: usize) -> BoardLookupTable {
+        let board_table = repeat_n(u32::MAX, (max_number + 1) * max_number_occurrence).collect();
+
+        BoardLookupTable {
+            max_number_occurrence,
+            board_table,
+        }
+    }
+
+    /// Iterates over all boards (their index respectively) that have the given number
+    fn iterate_boards(&self, number: u8) -> &[u32] {
+        let start = self.max_number_occurrence * number as usize;
+        let end = self.max_number_occurrence * (number as usize + 1);
+        &self.board_table[start..end]
+    }
+
+    fn add_element(&mut self, board_id: u32, number: u8) {
+        let number = number as usize;
+        // Find next free space and set the board_id
+        for i in 0..self.max_number_occurrence {
+            if self.board_table[number * self.max_number_occurrence + i] == u32::MAX {
+                self.board_table[number * self.max_number_occurrence + i] = board_id;
+                return;
+            }
+        }
+    }
+}
+
+/// The drawn numbers together with all boards, none of them marked yet
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    bingo_sequence: Vec<u8>,
+    search_table: BoardLookupTable,
+    shape: Shape,
+    boards: Vec<Board>,
+    patterns: Vec<Pattern>,
+}
+
+/// A board that has won, and how
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    /// Index of the draw in the sequence
+    pub draw: usize,
+    /// The number that completed the pattern
+    pub number: u8,
+    pub pattern: PatternKind,
+    /// The sum of the unmarked numbers times the last number
+    pub score: i32,
+}
+
+/// The outcome of a whole game
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Ranking {
+    /// In the order the boards won. Boards winning with the same number are in the order of the input
+    pub wins: Vec<Win>,
+    /// The boards that have not won once all numbers are drawn
+    pub never: Vec<usize>,
+}
+
+impl Ranking {
+    pub fn first(&self) -> Option<&Win> {
+        self.wins.first()
+    }
+
+    /// The board that wins after all others, `None` if not every board wins
+    pub fn last(&self) -> Option<&Win> {
+        if self.never.is_empty() { self.wins.last() } else { None }
+    }
+}
+
+impl Display for Ranking {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:>5} {:>6} {:>5} {:>7}  {:<16} {:>7}", "Rank", "Board", "Draw", "Number", "Pattern", "Score")?;
+        for (rank, win) in self.wins.iter().enumerate() {
+            writeln!(f, "{:>5} {:>6} {:>5} {:>7}  {:<16} {:>7}",
+                     rank + 1, win.board + 1, win.draw + 1, win.number, win.pattern.to_string(), win.score)?;
+        }
+        if !self.never.is_empty() {
+            writeln!(f, "Never won: {}", self.never.iter().map(|b| b + 1).join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Bingo {
+    pub fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    /// Plays with the patterns of the given rules instead of rows and columns
+    pub fn with_rules(mut self, rules: &[Rule]) -> Bingo {
+        self.patterns = pattern::patterns(self.shape, rules);
+        self
+    }
+
+    /// Draws the numbers until every board has won or none are left
+    pub fn play(&self) -> Ranking {
+        self.play_draws(self.bingo_sequence.len()).1
+    }
+
+    /// Draws only the first `draws` numbers. Returns the boards as they are then, boards that have
+    /// won are not marked any further. Boards that have not won yet are listed as never winning
+    pub fn play_draws(&self, draws: usize) -> (Vec<Board>, Ranking) {
+        self.play_sequence(&self.bingo_sequence[..draws.min(self.bingo_sequence.len())])
+    }
+
+    /// Plays with other drawn numbers. The lookup table only knows the numbers of the input,
+    /// so `sequence` has to be made of them, e.g. a shuffled copy of them
+    pub fn play_sequence(&self, sequence: &[u8]) -> (Vec<Board>, Ranking) {
+        let mut boards = self.boards.clone();
+        let mut won = vec![false; boards.len()];
+        let mut wins = Vec::new();
+
+        // Now play bingo :)
+        for (draw, &s) in sequence.iter().enumerate() {
+            // Lookup all boards this number has to be added to
+            for &b in self.search_table.iterate_boards(s) {
+                // Endmarker - could be abstracted in custom iterator
+                if b == u32::MAX {
+                    break;
+                }
+                let (board_id, board) = (b as usize, &mut boards[b as usize]);
+                if won[board_id] {
+                    continue;
+                }
+                board.occupy_number(s);
+                if let Some(pattern) = board.winning_pattern(&self.patterns) {
+                    won[board_id] = true;
+                    let score = board.sum_unmarked_numbers() * s as i32;
+                    wins.push(Win { board: board_id, draw, number: s, pattern: pattern.kind, score });
+                }
+            }
+            debug!("Sequence = {}, boards won = {}", s, wins.len());
+            if wins.len() == boards.len() {
+                break;
+            }
+        }
+        let never = won.iter().positions(|&won| !won).collect();
+        (boards, Ranking { wins, never })
+    }
+
+    pub fn sequence(&self) -> &[u8] {
+        &self.bingo_sequence
+    }
+
+    /// The pattern of that kind in the current rules
+    pub fn pattern(&self, kind: PatternKind) -> Option<&Pattern> {
+        self.patterns.iter().find(|pattern| pattern.kind == kind)
+    }
+}
+
+fn report(win: &Win) -> i32 {
+    progress!("Found board {} at sequence {} with {}", win.board, win.number, win.pattern);
+    progress!("Score = {}", win.score);
+    win.score
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const DEFAULT_INPUT: &'static str = "day04/src/input.txt";
+
+    type Input = Bingo;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Bingo, ParseError> {
+        let (bingo_sequence, search_table, shape, boards) = parse_boards(input)?;
+        let patterns = pattern::lines(shape);
+        Ok(Bingo { bingo_sequence, search_table, shape, boards, patterns })
+    }
+
+    fn part1(bingo: &Bingo) -> Result<i32, NoAnswer> {
+        let ranking = bingo.play();
+        ranking.first().map(report).ok_or_else(|| NoAnswer::new("no board wins"))
+    }
+
+    /// Only if every board wins, otherwise the boards that never do are named
+    fn part2(bingo: &Bingo) -> Result<i32, NoAnswer> {
+        let ranking = bingo.play();
+        ranking.last().map(report).ok_or_else(|| {
+            let never = ranking.never.iter().map(|board| board + 1).join(", ");
+            match ranking.never.len() {
+                1 => NoAnswer::new(format!("board {} never wins", never)),
+                _ => NoAnswer::new(format!("boards {} never win", never)),
+            }
+        })
+    }
+}
+
+/// Reads the drawn numbers and the boards. The boards are separated by empty lines and
+/// all of them have as many rows and columns as the first one
+fn parse_boards(input: &str) -> Result<(Vec<u8>, BoardLookupTable, Shape, Vec<Board>), ParseError> {
+    let mut lines = Span::lines(input);
+    let bingo_sequence: Vec<u8> = match lines.next() {
+        Some(line) => line.trim().split(',')
+            .map(|n| n.trim().parse::<u8>("a drawn number"))
+            .collect::<Result<_, _>>()?,
+        None => return Err(ParseError::at_end_of(input, "the drawn numbers")),
+    };
+
+    debug!("Sequence = {}", bingo_sequence.iter()
+        .fold(String::new(), |mut a, &b| {
+            a.push_str(&(b.to_string() + " "));
+            a
+        }));
+
+    // The rows of all boards, and the empty line that ended each board
+    let mut blocks: Vec<(Vec<Vec<u8>>, Option<Span>)> = Vec::new();
+    let mut rows: Vec<Vec<u8>> = Vec::new();
+    let mut columns = None;
+    for line in lines.map(|l| l.trim()) {
+        if line.is_empty() {
+            if !rows.is_empty() {
+                blocks.push((std::mem::take(&mut rows), Some(line)));
+            }
+            continue;
+        }
+        let numbers = line.split_whitespace().collect_vec();
+        let expected = *columns.get_or_insert(numbers.len());
+        if numbers.len() > expected {
+            return Err(numbers[expected].error("the end of the row"));
+        }
+        if numbers.len() < expected {
+            return Err(line.end().error(format!("{} numbers in the row", expected)));
+        }
+        if let Some((first, _)) = blocks.first() {
+            if rows.len() == first.len() {
+                return Err(line.error(format!("an empty line after {} rows", first.len())));
+            }
+        }
+        rows.push(numbers.iter().map(|n| n.parse::<u8>("a board number")).collect::<Result<_, _>>()?);
+    }
+    if !rows.is_empty() {
+        blocks.push((rows, None));
+    }
+
+    let shape = match (blocks.first(), columns) {
+        (Some((first, _)), Some(columns)) => Shape { rows: first.len(), columns },
+        _ => return Err(ParseError::at_end_of(input, "a board")),
+    };
+    if let Some((_, end)) = blocks.iter().find(|(rows, _)| rows.len() < shape.rows) {
+        let expected = format!("{} rows like the first board", shape.rows);
+        return Err(end.map_or_else(|| ParseError::at_end_of(input, &expected), |end| end.error(&expected)));
+    }
+    let boards = blocks.into_iter()
+        .map(|(rows, _)| Board::new(shape, rows.concat()))
+        .collect_vec();
+
+    // Construct search table, boards may contain numbers that are never drawn
+    let all_numbers = boards.iter().flat_map(|b| b.numbers());
+    let max_number = bingo_sequence.iter().chain(all_numbers.clone()).max().unwrap();
+    // Find maximum count of one number
+    let max_number_count = *all_numbers.counts().values()
+        .max().unwrap();
+
+    let mut search_table = BoardLookupTable::new(*max_number as usize, max_number_count);
+    for (board_id, board) in boards.iter().enumerate() {
+        for &number in board.numbers() {
+            search_table.add_element(board_id as u32, number);
+        }
+    }
+    Ok((bingo_sequence, search_table, shape, boards))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day04;
+        sample: "test.txt" => 4512, 1924;
+    }
+
+    const SHAPE: Shape = Shape { rows: 5, columns: 5 };
+    const NUMBERS: [u8; 25] = [22, 13, 17, 11, 0, 8, 2, 23, 4, 24, 21, 9, 14, 16, 7, 6, 10, 3, 18, 5, 1, 12, 20, 15, 19];
+
+    fn board_with(marked: &[u8]) -> Board {
+        let mut board = Board::new(SHAPE, NUMBERS.to_vec());
+        for &number in marked {
+            board.occupy_number(number);
+        }
+        board
+    }
+
+    #[test]
+    fn test_has_won() {
+        let patterns = pattern::lines(SHAPE);
+        // Horizontal
+        assert!(board_with(&[8, 2, 23, 4, 24]).has_won(&patterns));
+        assert!(!board_with(&[8, 2, 23, 4, 21]).has_won(&patterns));
+
+        // Vertical
+        let board = board_with(&[11, 4, 16, 18, 15]);
+        assert_eq!(board.winning_pattern(&patterns).map(|p| p.kind), Some(pattern::PatternKind::Column(3)));
+        assert!(!board_with(&[11, 4, 16, 18, 19]).has_won(&patterns));
+    }
+
+    #[test]
+    fn test_occupy_number() {
+        let mut board1 = board_with(&[22, 13, 17, 8, 4]);
+        assert_eq!(board1.marked(), &mask::Mask::Small(0b1_0010_0111));
+        assert_eq!(board1.occupy_number(99), None);
+        assert_eq!(board1.occupy_number(19), Some(24));
+    }
+
+    #[test]
+    fn test_sum_marked() {
+        let mut board1 = board_with(&[22, 13]);
+        assert_eq!(board1.sum_marked_numbers(), 22 + 13);
+        board1.occupy_number(11);
+        board1.occupy_number(24);
+        assert_eq!(board1.sum_marked_numbers(), 22 + 13 + 11 + 24);
+        assert_eq!(board1.sum_unmarked_numbers(), 300 - 22 - 13 - 11 - 24);
+    }
+
+    #[test]
+    fn test_board_sizes() {
+        let bingo = Day04::parse("1,2,3,4,5\n\n1 2 3\n4 5 6\n\n7 8 9\n1 3 5\n").unwrap();
+        assert_eq!(bingo.shape, Shape { rows: 2, columns: 3 });
+        assert_eq!(Day04::part1(&bingo), Ok((4 + 5 + 6) * 3));
+        assert_eq!(Day04::part2(&bingo), Ok((7 + 8 + 9) * 5));
+        assert_eq!(bingo.play().last().map(|win| win.pattern), Some(PatternKind::Row(1)));
+
+        // 81 cells need two words
+        let numbers = (0..81).map(|n| n.to_string()).collect_vec();
+        let board = numbers.chunks(9).map(|row| row.join(" ")).join("\n");
+        let bingo = Day04::parse(&format!("{}\n\n{}\n", (0..81).step_by(9).join(","), board)).unwrap();
+        assert!(matches!(bingo.boards[0].marked(), mask::Mask::Wide(_)));
+        assert_eq!(Day04::part1(&bingo), Ok(((0..81).sum::<i32>() - (0..81).step_by(9).sum::<i32>()) * 72));
+    }
+
+    #[test]
+    fn test_rules() {
+        let bingo = Day04::parse(include_str!("test.txt")).unwrap();
+        let win = bingo.play().first().cloned().unwrap();
+        assert_eq!((win.board, win.number, win.pattern, win.score), (2, 24, PatternKind::Row(0), 4512));
+
+        // The corners of the third board are 14, 4, 2 and 7, which are all drawn first
+        let win = bingo.clone().with_rules(&[Rule::Corners]).play().first().cloned().unwrap();
+        assert_eq!((win.board, win.number, win.pattern), (2, 14, PatternKind::Corners));
+
+        let win = bingo.clone().with_rules(&[Rule::Diagonals]).play().first().cloned().unwrap();
+        assert!(matches!(win.pattern, PatternKind::Diagonal | PatternKind::AntiDiagonal));
+
+        let custom = pattern::parse_custom(SHAPE, "#####/...../...../...../.....").unwrap();
+        let win = bingo.clone().with_rules(&[Rule::Blackout, Rule::Custom(custom)]).play().first().cloned().unwrap();
+        assert_eq!((win.pattern, win.score), (PatternKind::Custom(0), 4512));
+        // All numbers are drawn, the first board needs the 1 which comes last
+        let win = bingo.with_rules(&[Rule::Blackout]).play().first().cloned().unwrap();
+        assert_eq!((win.board, win.pattern), (1, PatternKind::Blackout));
+    }
+
+    #[test]
+    fn test_ranking() {
+        let bingo = Day04::parse(include_str!("test.txt")).unwrap();
+        let ranking = bingo.play();
+        let order = ranking.wins.iter().map(|win| (win.board, win.draw, win.number, win.score)).collect_vec();
+        assert_eq!(order, [(2, 11, 24, 4512), (0, 13, 16, 2192), (1, 14, 13, 1924)]);
+        assert!(ranking.never.is_empty());
+
+        // None of the numbers of the second board are drawn
+        let bingo = Day04::parse("1,2,3\n\n1 2\n3 4\n\n5 6\n7 8\n").unwrap();
+        let ranking = bingo.play();
+        assert_eq!(ranking.wins.len(), 1);
+        assert_eq!(ranking.never, [1]);
+        assert_eq!(ranking.last(), None);
+        assert!(ranking.to_string().ends_with("Never won: 2\n"));
+        assert_eq!(Day04::part1(&bingo), Ok((3 + 4) * 2));
+        assert_eq!(Day04::part2(&bingo), Err(NoAnswer::new("board 2 never wins")));
+
+        let bingo = Day04::parse("9\n\n1 2\n3 4\n\n5 6\n7 8\n").unwrap();
+        assert_eq!(Day04::part1(&bingo), Err(NoAnswer::new("no board wins")));
+        assert_eq!(Day04::part2(&bingo), Err(NoAnswer::new("boards 1, 2 never win")));
+    }
+
+    #[test]
+    fn test_malformed_boards() {
+        let error = Day04::parse("1,2\n\n1 2\n3 4\n\n5 6\n7\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (7, "2 numbers in the row"));
+        let error = Day04::parse("1,2\n\n1 2\n3 4\n\n5 6\n7 8\n9 1\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (8, "an empty line after 2 rows"));
+        let error = Day04::parse("1,2\n\n1 2\n3 4\n\n5 6\n\n7 8\n1 2\n").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (7, "2 rows like the first board"));
+    }
+
+    #[test]
+    fn test_construction() {
+        let input = include_str!("test.txt");
+        let (_, search_table, shape, boards) = parse_boards(input).unwrap();
+        assert_eq!(shape, SHAPE);
+        let board1: [u8;25] = [22, 13, 17, 11, 0, 8, 2, 23, 4, 24, 21, 9, 14, 16, 7, 6, 10, 3, 18, 5, 1, 12, 20, 15, 19];
+        let board2: [u8;25] = [3, 15, 0, 2, 22, 9, 18, 13, 17, 5, 19, 8, 7, 25, 23, 20, 11, 10, 24, 4, 14, 21, 16, 12, 6];
+        let board3: [u8;25] = [14, 21, 17, 24, 4, 10, 16, 15, 9, 19, 18, 8, 23, 26, 20, 22, 11, 13, 6, 5, 2, 0, 12, 3, 7];
+        assert_eq!(board1, boards[0].numbers());
+        assert_eq!(board2, boards[1].numbers());
+        assert_eq!(board3, boards[2].numbers());
+
+        let it1 = search_table.iterate_boards(7);
+        assert_eq!(it1[0], 0);
+        assert_eq!(it1[1], 1);
+        assert_eq!(it1[2], 2);
+        assert_eq!(it1.get(3), None);
+
+        let it1 = search_table.iterate_boards(11);
+        assert_eq!(it1[0], 0);
+        assert_eq!(it1[1], 1);
+        assert_eq!(it1[2], 2);
+        assert_eq!(it1.get(3), None);
+
+        let it1 = search_table.iterate_boards(26);
+        assert_eq!(it1[0], 2);
+        assert_eq!(it1[1], u32::MAX);
+        assert_eq!(it1[2], u32::MAX);
+        assert_eq!(it1.get(3), None);
+
+    }
+}
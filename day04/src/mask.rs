@@ -0,0 +1,87 @@
+//! Bitboards of the cells of a board. Cell `i` is bit `i`, counted row by row from the top left.
+//! Boards of up to 64 cells fit into a single word, larger ones take as many words as they need
+
+/// A set of cells of a board
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Mask {
+    Small(u64),
+    Wide(Vec<u64>),
+}
+
+impl Mask {
+    /// No cell of a board with `cells` cells
+    pub fn empty(cells: usize) -> Mask {
+        if cells <= 64 {
+            Mask::Small(0)
+        } else {
+            Mask::Wide(vec![0; cells.div_ceil(64)])
+        }
+    }
+
+    pub fn of(cells: usize, set: impl IntoIterator<Item = usize>) -> Mask {
+        let mut mask = Mask::empty(cells);
+        set.into_iter().for_each(|cell| mask.insert(cell));
+        mask
+    }
+
+    pub fn insert(&mut self, cell: usize) {
+        match self {
+            Mask::Small(bits) => *bits |= 1 << cell,
+            Mask::Wide(words) => words[cell / 64] |= 1 << (cell % 64),
+        }
+    }
+
+    pub fn remove(&mut self, cell: usize) {
+        match self {
+            Mask::Small(bits) => *bits &= !(1 << cell),
+            Mask::Wide(words) => words[cell / 64] &= !(1 << (cell % 64)),
+        }
+    }
+
+    pub fn contains(&self, cell: usize) -> bool {
+        match self {
+            Mask::Small(bits) => (bits >> cell) & 1 == 1,
+            Mask::Wide(words) => (words[cell / 64] >> (cell % 64)) & 1 == 1,
+        }
+    }
+
+    /// Whether every cell of `other` is also in this mask
+    pub fn covers(&self, other: &Mask) -> bool {
+        match (self, other) {
+            (Mask::Small(bits), Mask::Small(other)) => bits & other == *other,
+            (Mask::Wide(words), Mask::Wide(other)) => words.iter().zip(other).all(|(w, o)| w & o == *o),
+            _ => panic!("Masks of boards of different sizes cannot be compared"),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        match self {
+            Mask::Small(bits) => bits.count_ones() as usize,
+            Mask::Wide(words) => words.iter().map(|w| w.count_ones() as usize).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small() {
+        let mut mask = Mask::of(25, [0, 1, 2, 3, 4]);
+        assert_eq!(mask, Mask::Small(0b11111));
+        assert!(mask.covers(&Mask::of(25, [1, 3])));
+        mask.remove(3);
+        assert!(!mask.covers(&Mask::of(25, [1, 3])));
+        assert_eq!(mask.count(), 4);
+    }
+
+    #[test]
+    fn test_wide() {
+        let mask = Mask::of(81, [0, 63, 64, 80]);
+        assert_eq!(mask, Mask::Wide(vec![1 | 1 << 63, 1 | 1 << 16]));
+        assert!(mask.contains(80) && !mask.contains(79));
+        assert!(mask.covers(&Mask::of(81, [63, 64])));
+        assert!(!mask.covers(&Mask::of(81, [62, 64])));
+    }
+}
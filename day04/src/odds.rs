@@ -0,0 +1,173 @@
+//! Estimates how likely each board is to win first and last when the numbers are drawn in a
+//! random order. Many games are played in parallel, every one with the drawn numbers of the input
+//! shuffled by its own random stream, so the result only depends on the seed and not on the threads
+
+use std::fmt::{Display, Formatter};
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use crate::{Bingo, Win};
+
+/// Games played one after another by a thread. The blocks are added up in order, so the
+/// floating point sums are the same for any number of threads
+const BLOCK: usize = 256;
+
+/// How many games each board won first and last. Boards winning with the same number share the game
+#[derive(Debug, Clone, PartialEq)]
+pub struct Odds {
+    pub games: usize,
+    pub first: Vec<f64>,
+    pub last: Vec<f64>,
+    /// Games without a last winner, because some board never won
+    pub no_last: usize,
+}
+
+impl Odds {
+    fn new(boards: usize) -> Odds {
+        Odds { games: 0, first: vec![0.0; boards], last: vec![0.0; boards], no_last: 0 }
+    }
+
+    pub fn first_probability(&self, board: usize) -> f64 {
+        self.first[board] / self.games as f64
+    }
+
+    /// Among the games with a last winner, `None` if there was no such game
+    pub fn last_probability(&self, board: usize) -> Option<f64> {
+        let finished = self.games - self.no_last;
+        (finished > 0).then(|| self.last[board] / finished as f64)
+    }
+
+    fn add(&mut self, other: &Odds) {
+        self.games += other.games;
+        self.no_last += other.no_last;
+        self.first.iter_mut().zip(&other.first).for_each(|(a, b)| *a += b);
+        self.last.iter_mut().zip(&other.last).for_each(|(a, b)| *a += b);
+    }
+}
+
+/// Splits one game between all boards that won with the same number as `win`
+fn share(credit: &mut [f64], wins: &[Win], win: Option<&Win>) {
+    if let Some(win) = win {
+        let tied = wins.iter().filter(|w| w.draw == win.draw).collect::<Vec<_>>();
+        for w in &tied {
+            credit[w.board] += 1.0 / tied.len() as f64;
+        }
+    }
+}
+
+fn play_block(bingo: &Bingo, games: std::ops::Range<usize>, seed: u64) -> Odds {
+    let mut odds = Odds { games: games.len(), ..Odds::new(bingo.boards.len()) };
+    let mut sequence = bingo.sequence().to_vec();
+    for game in games {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        rng.set_stream(game as u64);
+        sequence.copy_from_slice(bingo.sequence());
+        sequence.shuffle(&mut rng);
+
+        let (_, ranking) = bingo.play_sequence(&sequence);
+        share(&mut odds.first, &ranking.wins, ranking.wins.first());
+        match ranking.last() {
+            Some(last) => share(&mut odds.last, &ranking.wins, Some(last)),
+            None => odds.no_last += 1,
+        }
+    }
+    odds
+}
+
+/// Plays `games` games with the drawn numbers of the input in random orders
+pub fn estimate(bingo: &Bingo, games: usize, seed: u64) -> Odds {
+    let blocks: Vec<Odds> = (0..games.div_ceil(BLOCK)).into_par_iter()
+        .map(|block| play_block(bingo, block * BLOCK..((block + 1) * BLOCK).min(games), seed))
+        .collect();
+    let mut odds = Odds::new(bingo.boards.len());
+    blocks.iter().for_each(|block| odds.add(block));
+    odds
+}
+
+/// The probabilities of every board with their standard errors, and how far they are
+/// from the share every board would have if the set was fair. Games without a last winner are
+/// only counted, the last winners are compared among the other games
+impl Display for Odds {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let finished = self.games - self.no_last;
+        let error = |p: f64, games: usize| (p * (1.0 - p) / games as f64).sqrt() * 100.0;
+        writeln!(f, "{:>6} {:>16} {:>16}", "Board", "First", "Last")?;
+        for board in 0..self.first.len() {
+            let first = self.first_probability(board);
+            write!(f, "{:>6} {:>7.2}% ± {:<5.2}", board + 1, first * 100.0, error(first, self.games))?;
+            match self.last_probability(board) {
+                Some(last) => writeln!(f, " {:>7.2}% ± {:.2}", last * 100.0, error(last, finished))?,
+                None => writeln!(f, " {:>8}", "n/a")?,
+            }
+        }
+
+        let fair = 100.0 / self.first.len() as f64;
+        let range = |counts: &[f64], games: usize| counts.iter().fold((f64::MAX, f64::MIN), |(lo, hi), &c| {
+            let p = c / games as f64 * 100.0;
+            (lo.min(p), hi.max(p))
+        });
+        let (first_low, first_high) = range(&self.first, self.games);
+        writeln!(f, "{} games, a fair set gives every board {:.2}%", self.games, fair)?;
+        if finished > 0 {
+            let (last_low, last_high) = range(&self.last, finished);
+            writeln!(f, "First: {:.2}% to {:.2}%, last: {:.2}% to {:.2}%", first_low, first_high, last_low, last_high)?;
+        } else {
+            writeln!(f, "First: {:.2}% to {:.2}%", first_low, first_high)?;
+        }
+        if self.no_last > 0 {
+            writeln!(f, "{} games had no last winner, some board never won", self.no_last)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::Day04;
+
+    #[test]
+    fn test_deterministic() {
+        let bingo = Day04::parse(include_str!("test.txt")).unwrap();
+        let odds = estimate(&bingo, 1000, 7);
+        assert_eq!(odds.games, 1000);
+        assert!((odds.first.iter().sum::<f64>() - 1000.0).abs() < 1e-9);
+        assert!((odds.last.iter().sum::<f64>() - 1000.0).abs() < 1e-9);
+        assert_eq!(odds.no_last, 0);
+        assert_eq!(odds.last_probability(2), Some(odds.last[2] / 1000.0));
+
+        let single = rayon::ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+        assert_eq!(single.install(|| estimate(&bingo, 1000, 7)), odds);
+        assert_ne!(estimate(&bingo, 1000, 8), odds);
+    }
+
+    #[test]
+    fn test_ties_are_shared() {
+        // Both boards hold the same numbers, they always win with the same draw
+        let bingo = Day04::parse("1,2,3,4\n\n1 2\n3 4\n\n1 3\n2 4\n").unwrap();
+        let odds = estimate(&bingo, 300, 0);
+        assert_eq!(odds.first, [150.0, 150.0]);
+        assert_eq!(odds.first_probability(1), 0.5);
+    }
+
+    #[test]
+    fn test_board_that_never_wins() {
+        // Board 2 holds no drawn number, so no game has a last winner
+        let bingo = Day04::parse("1,2,3,4\n\n1 2\n3 4\n\n5 6\n7 8\n\n1 3\n9 9\n").unwrap();
+        let odds = estimate(&bingo, 300, 0);
+        assert_eq!(odds.no_last, 300);
+        assert_eq!(odds.last, [0.0, 0.0, 0.0]);
+        assert!((odds.first.iter().sum::<f64>() - 300.0).abs() < 1e-9);
+        assert_eq!(odds.first[1], 0.0);
+        assert_eq!(odds.last_probability(0), None);
+
+        let text = odds.to_string();
+        assert!(text.contains("300 games had no last winner"));
+        assert!(!text.contains("last:"));
+    }
+}
@@ -0,0 +1,168 @@
+//! The sets of cells that win a board once all of them are marked
+
+use std::fmt::{Display, Formatter};
+
+use crate::board::Shape;
+use crate::mask::Mask;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternKind {
+    Row(usize),
+    Column(usize),
+    /// From the top left corner down to the right
+    Diagonal,
+    /// From the top right corner down to the left
+    AntiDiagonal,
+    Corners,
+    Blackout,
+    /// The patterns given by the user, numbered from 0
+    Custom(usize),
+}
+
+impl Display for PatternKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternKind::Row(row) => write!(f, "row {}", row + 1),
+            PatternKind::Column(column) => write!(f, "column {}", column + 1),
+            PatternKind::Diagonal => write!(f, "diagonal"),
+            PatternKind::AntiDiagonal => write!(f, "anti-diagonal"),
+            PatternKind::Corners => write!(f, "four corners"),
+            PatternKind::Blackout => write!(f, "blackout"),
+            PatternKind::Custom(index) => write!(f, "custom pattern {}", index + 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pub kind: PatternKind,
+    pub mask: Mask,
+}
+
+/// A group of patterns that wins a game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rule {
+    /// Every full row and every full column, the rules of the puzzle
+    Lines,
+    /// Both diagonals. On boards that are not square they start at the top corners
+    /// and end where they reach the bottom or a side
+    Diagonals,
+    Corners,
+    Blackout,
+    Custom(Mask),
+}
+
+impl Rule {
+    /// One of `lines`, `diagonals`, `corners` and `blackout`
+    pub fn from_name(name: &str) -> Option<Rule> {
+        match name {
+            "lines" => Some(Rule::Lines),
+            "diagonals" => Some(Rule::Diagonals),
+            "corners" => Some(Rule::Corners),
+            "blackout" => Some(Rule::Blackout),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a custom pattern drawn with `#` for the cells that have to be marked and `.` for the others,
+/// the rows separated by `/`, e.g. `#...#/...../..#../...../#...#`
+pub fn parse_custom(shape: Shape, text: &str) -> Result<Mask, String> {
+    let rows: Vec<&str> = text.split('/').collect();
+    if rows.len() != shape.rows {
+        return Err(format!("The pattern '{}' has {} rows instead of {}", text, rows.len(), shape.rows));
+    }
+    let mut mask = Mask::empty(shape.cells());
+    for (row, line) in rows.iter().enumerate() {
+        if line.chars().count() != shape.columns {
+            return Err(format!("Row {} of the pattern '{}' does not have {} cells", row + 1, text, shape.columns));
+        }
+        for (column, c) in line.chars().enumerate() {
+            match c {
+                '#' => mask.insert(shape.cell(row, column)),
+                '.' => {}
+                _ => return Err(format!("The pattern '{}' may only contain '#', '.' and '/'", text)),
+            }
+        }
+    }
+    if mask.count() == 0 {
+        return Err(format!("The pattern '{}' has no cell to mark", text));
+    }
+    Ok(mask)
+}
+
+/// The patterns of all rules, in the order of the rules
+pub fn patterns(shape: Shape, rules: &[Rule]) -> Vec<Pattern> {
+    let cells = shape.cells();
+    let pattern = |kind, set: &mut dyn Iterator<Item = usize>| Pattern { kind, mask: Mask::of(cells, set) };
+    let mut patterns = Vec::new();
+    let mut custom = 0;
+    for rule in rules {
+        match rule {
+            Rule::Lines => {
+                for row in 0..shape.rows {
+                    patterns.push(pattern(PatternKind::Row(row), &mut (0..shape.columns).map(|column| shape.cell(row, column))));
+                }
+                for column in 0..shape.columns {
+                    patterns.push(pattern(PatternKind::Column(column), &mut (0..shape.rows).map(|row| shape.cell(row, column))));
+                }
+            }
+            Rule::Diagonals => {
+                let length = shape.rows.min(shape.columns);
+                patterns.push(pattern(PatternKind::Diagonal, &mut (0..length).map(|i| shape.cell(i, i))));
+                patterns.push(pattern(PatternKind::AntiDiagonal, &mut (0..length).map(|i| shape.cell(i, shape.columns - 1 - i))));
+            }
+            Rule::Corners => {
+                let (last_row, last_column) = (shape.rows - 1, shape.columns - 1);
+                let corners = [(0, 0), (0, last_column), (last_row, 0), (last_row, last_column)];
+                patterns.push(pattern(PatternKind::Corners, &mut corners.iter().map(|&(row, column)| shape.cell(row, column))));
+            }
+            Rule::Blackout => patterns.push(pattern(PatternKind::Blackout, &mut (0..cells))),
+            Rule::Custom(mask) => {
+                patterns.push(Pattern { kind: PatternKind::Custom(custom), mask: mask.clone() });
+                custom += 1;
+            }
+        }
+    }
+    patterns
+}
+
+/// Every full row and every full column, the rules of the puzzle
+pub fn lines(shape: Shape) -> Vec<Pattern> {
+    patterns(shape, &[Rule::Lines])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let patterns = lines(Shape { rows: 3, columns: 4 });
+        assert_eq!(patterns.len(), 7);
+        assert_eq!(patterns[1].mask, Mask::Small(0b1111_0000));
+        assert_eq!(patterns[4].mask, Mask::Small(0b0010_0010_0010));
+        assert_eq!(patterns[6].kind.to_string(), "column 4");
+    }
+
+    #[test]
+    fn test_other_rules() {
+        let shape = Shape { rows: 3, columns: 3 };
+        let custom = parse_custom(shape, ".#./###/.#.").unwrap();
+        let patterns = patterns(shape, &[Rule::Diagonals, Rule::Corners, Rule::Blackout, Rule::Custom(custom)]);
+        let masks: Vec<_> = patterns.iter().map(|p| p.mask.clone()).collect();
+        assert_eq!(masks, [Mask::Small(0b100_010_001), Mask::Small(0b001_010_100), Mask::Small(0b101_000_101),
+            Mask::Small(0b111_111_111), Mask::Small(0b010_111_010)]);
+        assert_eq!(patterns[4].kind, PatternKind::Custom(0));
+    }
+
+    #[test]
+    fn test_parse_custom() {
+        let shape = Shape { rows: 2, columns: 3 };
+        assert_eq!(parse_custom(shape, "#../..#"), Ok(Mask::Small(0b100_001)));
+        assert!(parse_custom(shape, "#../..#/...").is_err());
+        assert!(parse_custom(shape, "#./..#").is_err());
+        assert!(parse_custom(shape, "x../..#").is_err());
+        assert!(parse_custom(shape, ".../...").is_err());
+    }
+}
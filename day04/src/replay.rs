@@ -0,0 +1,315 @@
+//! Replays a game in the terminal. All boards are laid out in a grid and the numbers are drawn
+//! one after another, either automatically or stepping with the keys
+
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crossterm::cursor::{Hide, MoveTo, MoveToNextLine, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Print, Stylize};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use itertools::Itertools;
+
+use crate::board::Board;
+use crate::mask::Mask;
+use crate::pattern::PatternKind;
+use crate::Bingo;
+
+const GAP: usize = 3;
+/// The lines above the boards, which stay in place while the boards scroll
+const HEADER: usize = 3;
+const KEYS: &str = "space play/pause  ←/→ step  home/end jump  +/- speed  ↑/↓ pgup/pgdn scroll  q quit";
+
+/// The game after a number of draws
+pub struct Frame {
+    pub draws: usize,
+    pub latest: Option<u8>,
+    pub boards: Vec<Board>,
+    /// How each board has won so far, with the cells of the winning pattern
+    pub wins: Vec<Option<(PatternKind, Mask)>>,
+}
+
+impl Frame {
+    pub fn new(bingo: &Bingo, draws: usize) -> Frame {
+        let (boards, ranking) = bingo.play_draws(draws);
+        let mut wins = vec![None; boards.len()];
+        for win in &ranking.wins {
+            let mask = bingo.pattern(win.pattern).expect("Wins come from the patterns of the rules").mask.clone();
+            wins[win.board] = Some((win.pattern, mask));
+        }
+        let latest = draws.checked_sub(1).map(|draw| bingo.sequence()[draw]);
+        Frame { draws, latest, boards, wins }
+    }
+
+    /// One board with its title, every line `width` characters wide and every number `digits` wide
+    fn board_lines(&self, id: usize, width: usize, digits: usize) -> Vec<String> {
+        let board = &self.boards[id];
+        let winning = self.wins[id].as_ref().map(|(_, mask)| mask);
+        // Padded before styling, the escape codes take no room on the screen
+        let title = match &self.wins[id] {
+            Some((kind, _)) => format!("{:<width$}", format!("Board {}: {}", id + 1, kind)).green().bold().to_string(),
+            None => format!("{:<width$}", format!("Board {}", id + 1)),
+        };
+        let mut lines = vec![title];
+
+        for (row, numbers) in board.numbers().chunks(board.shape().columns).enumerate() {
+            let line = numbers.iter().enumerate().map(|(column, &number)| {
+                let cell = board.shape().cell(row, column);
+                let text = format!("{:>digits$}", number);
+                let styled = if Some(number) == self.latest && board.marked().contains(cell) {
+                    text.black().on_yellow().to_string()
+                } else if winning.is_some_and(|mask| mask.contains(cell)) {
+                    text.black().on_green().to_string()
+                } else if board.marked().contains(cell) {
+                    text.red().to_string()
+                } else {
+                    text
+                };
+                format!(" {}", styled)
+            }).join("");
+            lines.push(format!("{}{}", line, " ".repeat(width - numbers.len() * (digits + 1))));
+        }
+        lines
+    }
+
+    /// The header and all boards, as many side by side as fit into `columns` characters
+    pub fn render(&self, bingo: &Bingo, columns: usize, speed: Duration, playing: bool) -> Vec<String> {
+        let total = bingo.sequence().len();
+        let latest = self.latest.map_or("-".to_string(), |number| number.to_string());
+        let state = if playing { "playing" } else { "paused" };
+        let mut lines = vec![
+            format!("Draw {}/{}  number {}  {} boards won  {} every {} ms",
+                    self.draws, total, latest.bold(), self.wins.iter().flatten().count(), state, speed.as_millis()),
+            KEYS.dim().to_string(),
+            String::new(),
+        ];
+
+        let shape = bingo.shape();
+        // Every cell has room for the largest number, drawn or not, and a space in front of it
+        let largest = self.boards.iter().flat_map(|board| board.numbers()).chain(bingo.sequence()).max();
+        let digits = largest.map_or(1, |number| number.to_string().len());
+        // Room for the longest title any board can get, so that the layout stays the same while playing
+        let title_width = bingo.patterns.iter()
+            .map(|pattern| format!("Board {}: {}", self.boards.len(), pattern.kind).chars().count())
+            .max().unwrap_or(0);
+        let width = (shape.columns * (digits + 1)).max(title_width);
+        let per_row = ((columns + GAP) / (width + GAP)).max(1);
+        for ids in &(0..self.boards.len()).chunks(per_row) {
+            let boards = ids.map(|id| self.board_lines(id, width, digits)).collect_vec();
+            for line in 0..=shape.rows {
+                lines.push(boards.iter().map(|b| b[line].as_str()).join(&" ".repeat(GAP)));
+            }
+            lines.push(String::new());
+        }
+        lines
+    }
+}
+
+/// Where the replay is and whether it advances by itself
+struct Player {
+    draws: usize,
+    total: usize,
+    playing: bool,
+    speed: Duration,
+    /// The first line of the boards on the screen
+    scroll: usize,
+    /// How many lines of the boards fit on the screen
+    page: usize,
+}
+
+impl Player {
+    /// Handles a key, `false` to quit
+    fn key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.playing = false;
+                self.draws = (self.draws + 1).min(self.total);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.playing = false;
+                self.draws = self.draws.saturating_sub(1);
+            }
+            KeyCode::Home => self.draws = 0,
+            KeyCode::End => self.draws = self.total,
+            KeyCode::Char('+') => self.speed = (self.speed / 2).max(Duration::from_millis(10)),
+            KeyCode::Char('-') => self.speed = (self.speed * 2).min(Duration::from_secs(10)),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll += 1,
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(self.page),
+            KeyCode::PageDown => self.scroll += self.page,
+            _ => {}
+        }
+        true
+    }
+
+    /// Fits the screen to `rows` lines and keeps the scrolling within the `lines` of a frame.
+    /// Returns the lines to draw: the header and the boards from `scroll` on
+    fn visible<'a>(&mut self, lines: &'a [String], rows: usize) -> Vec<&'a str> {
+        let (header, boards) = lines.split_at(HEADER.min(lines.len()));
+        self.page = rows.saturating_sub(HEADER).max(1);
+        self.scroll = self.scroll.min(boards.len().saturating_sub(self.page));
+        header.iter().chain(boards.iter().skip(self.scroll).take(self.page)).map(String::as_str).collect()
+    }
+
+    fn tick(&mut self) {
+        if self.draws < self.total {
+            self.draws += 1;
+        } else {
+            self.playing = false;
+        }
+    }
+}
+
+fn draw(out: &mut impl Write, lines: &[&str]) -> io::Result<()> {
+    queue!(out, MoveTo(0, 0), Clear(ClearType::All))?;
+    for line in lines {
+        queue!(out, Print(line), MoveToNextLine(1))?;
+    }
+    out.flush()
+}
+
+fn event_loop(out: &mut impl Write, bingo: &Bingo, player: &mut Player) -> io::Result<()> {
+    loop {
+        let (columns, rows) = terminal::size()?;
+        let frame = Frame::new(bingo, player.draws);
+        let lines = frame.render(bingo, columns as usize, player.speed, player.playing);
+        draw(out, &player.visible(&lines, rows as usize))?;
+
+        let timeout = if player.playing { player.speed } else { Duration::from_secs(3600) };
+        if event::poll(timeout)? {
+            // Anything else than a key press, like a resize, only draws the frame again
+            if let Event::Key(KeyEvent { code, kind: KeyEventKind::Press, .. }) = event::read()? {
+                if !player.key(code) {
+                    return Ok(());
+                }
+            }
+        } else {
+            player.tick();
+        }
+    }
+}
+
+/// The terminal in raw mode on the alternate screen. Dropping it restores the terminal,
+/// also when the replay fails or panics
+struct RawScreen;
+
+impl RawScreen {
+    fn enter(out: &mut impl Write) -> io::Result<RawScreen> {
+        terminal::enable_raw_mode()?;
+        // From here on the terminal has to be restored, even if the alternate screen cannot be entered
+        let screen = RawScreen;
+        execute!(out, EnterAlternateScreen, Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for RawScreen {
+    fn drop(&mut self) {
+        // There is nobody to report an error to, restore as much as possible
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Replays the game in the alternate screen until the user quits. `speed` is the time between two draws
+pub fn run(bingo: &Bingo, speed: Duration) -> io::Result<()> {
+    let mut player = Player { draws: 0, total: bingo.sequence().len(), playing: true, speed, scroll: 0, page: 1 };
+    let mut out = io::stdout();
+    let _screen = RawScreen::enter(&mut out)?;
+    event_loop(&mut out, bingo, &mut player)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Solution;
+
+    use super::*;
+    use crate::pattern::Rule;
+    use crate::Day04;
+
+    #[test]
+    fn test_frame() {
+        let bingo = Day04::parse(include_str!("test.txt")).unwrap();
+        let frame = Frame::new(&bingo, 12);
+        assert_eq!(frame.latest, Some(24));
+        assert_eq!(frame.wins[2].as_ref().map(|(kind, _)| *kind), Some(PatternKind::Row(0)));
+        assert!(frame.wins[0].is_none() && frame.wins[1].is_none());
+        assert!(Frame::new(&bingo, 0).latest.is_none());
+
+        // Two boards fit next to each other, as wide as the longest title `Board 3: column 5`
+        let lines = frame.render(&bingo, 40, Duration::from_millis(500), false);
+        assert_eq!(lines.len(), 3 + 2 * 7);
+        assert!(lines[3].starts_with("Board 1 "));
+        assert!(lines[3].contains("Board 2 "));
+        assert!(lines[10].contains("Board 3: row 1"));
+        assert_eq!(frame.render(&bingo, 36, Duration::from_millis(500), false).len(), 3 + 3 * 7);
+    }
+
+    /// The text of a line without the escape codes of its colors
+    fn without_styles(line: &str) -> String {
+        let mut text = String::new();
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                chars.by_ref().find(|&c| c == 'm');
+            } else {
+                text.push(c);
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn test_wide_numbers() {
+        // 100 is drawn, 250 is not but still needs room
+        let bingo = Day04::parse("100,1\n\n100 1\n2 250\n").unwrap();
+        let lines = Frame::new(&bingo, 2).render(&bingo, 80, Duration::from_millis(500), false);
+        let rows = lines[4..6].iter().map(|line| without_styles(line)).collect_vec();
+        assert_eq!(rows, ["100   1", "  2 250"].map(|row| format!(" {:<width$}", row, width = "Board 1: column 2".len() - 1)));
+    }
+
+    #[test]
+    fn test_title_width() {
+        let bingo = Day04::parse(include_str!("test.txt")).unwrap().with_rules(&[Rule::Diagonals]);
+        let frame = Frame::new(&bingo, 0);
+        let lines = frame.render(&bingo, 100, Duration::from_millis(500), false);
+        assert_eq!(lines[3].find("Board 2"), Some("Board 3: anti-diagonal".len() + GAP));
+    }
+
+    #[test]
+    fn test_player() {
+        let mut player = Player { draws: 0, total: 3, playing: true, speed: Duration::from_millis(100), scroll: 0, page: 1 };
+        player.tick();
+        assert!(player.key(KeyCode::Right));
+        assert_eq!((player.draws, player.playing), (2, false));
+        player.key(KeyCode::End);
+        player.tick();
+        assert_eq!(player.draws, 3);
+        player.key(KeyCode::Left);
+        player.key(KeyCode::Char('+'));
+        assert_eq!((player.draws, player.speed), (2, Duration::from_millis(50)));
+        assert!(!player.key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn test_scrolling() {
+        let mut player = Player { draws: 0, total: 3, playing: false, speed: Duration::from_millis(100), scroll: 0, page: 1 };
+        let lines = (0..HEADER + 10).map(|line| line.to_string()).collect_vec();
+        assert_eq!(player.visible(&lines, 7), ["0", "1", "2", "3", "4", "5", "6"]);
+
+        // Paging down stops at the last full screen
+        player.key(KeyCode::PageDown);
+        assert_eq!(player.visible(&lines, 7), ["0", "1", "2", "7", "8", "9", "10"]);
+        player.key(KeyCode::PageDown);
+        assert_eq!(player.visible(&lines, 7), ["0", "1", "2", "9", "10", "11", "12"]);
+        player.key(KeyCode::Up);
+        assert_eq!(player.scroll, 5);
+
+        // A taller screen shows all boards again
+        assert_eq!(player.visible(&lines, 20).len(), HEADER + 10);
+        assert_eq!(player.scroll, 0);
+    }
+}
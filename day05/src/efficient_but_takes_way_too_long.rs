@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter, write};
 use std::hash::Hash;
+use aoc_common::InputSource;
 use itertools::Itertools;
 use crate::LineType::{Diagonal, Horizontal, Vertical};
 
@@ -82,8 +83,7 @@ fn parse(content: &str) -> Vec<Line> {
     lines
 }
 
-fn part1() {
-    let content = include_str!("test.txt");
+fn part1(content: &str) {
     let lines = parse(content);
     // Sort by ending position
     let mut horizontal_positions = lines.iter()
@@ -123,5 +123,11 @@ fn part1() {
 }
 
 fn main() {
-    part1();
+    let source = std::env::args().nth(1)
+        .map(|arg| InputSource::from_arg(&arg))
+        .unwrap_or_default();
+    // Defaults to the sample as this approach takes way too long for the real input
+    let content = source.read("day05/src/test.txt")
+        .expect("Could not read input");
+    part1(&content);
 }
@@ -0,0 +1,33 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use rand::Rng;
+
+use crate::Day05;
+
+/// 500 horizontal, vertical and diagonal lines per scale. The area of the
+/// ocean floor grows with the scale, so the lines overlap about as often as in the real input
+impl Generate for Day05 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        let side = config.scaled_side(1000) as i32;
+        for _ in 0..config.scaled(500) {
+            let (x1, y1) = (rng.gen_range(0..side), rng.gen_range(0..side));
+            let (x2, y2) = match rng.gen_range(0..3) {
+                0 => (rng.gen_range(0..side), y1),
+                1 => (x1, rng.gen_range(0..side)),
+                _ => {
+                    let dx = if rng.gen() { 1 } else { -1 };
+                    let dy = if rng.gen() { 1 } else { -1 };
+                    let space_x = if dx > 0 { side - 1 - x1 } else { x1 };
+                    let space_y = if dy > 0 { side - 1 - y1 } else { y1 };
+                    let length = rng.gen_range(0..=space_x.min(space_y));
+                    (x1 + dx * length, y1 + dy * length)
+                }
+            };
+            writeln!(input, "{},{} -> {},{}", x1, y1, x2, y2).unwrap();
+        }
+        input
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use aoc_common::{NoAnswer, ParseError, Solution, Span};
+
+use crate::Line::{Diagonal, Horizontal, Vertical};
+
+mod generator;
+
+#[derive(PartialOrd, PartialEq, Hash, Ord, Eq, Copy, Clone)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Copy, Clone)]
+pub enum Line {
+    Horizontal(Point, Point),
+    Vertical(Point, Point),
+    Diagonal(Point, Point),
+}
+
+pub struct PointIterator {
+    line: Line,
+    position: i32,
+}
+
+impl PointIterator {
+    pub fn from(line: Line) -> PointIterator {
+        PointIterator {
+            line,
+            position: -1,
+        }
+    }
+}
+
+impl Iterator for PointIterator {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.line {
+            Horizontal(p1, p2) => {
+                if (p2.x - p1.x).abs() <= self.position {
+                    None
+                } else {
+                    self.position += 1;
+                    Some(Point { x: p1.x.min(p2.x) + self.position, y: p1.y })
+                }
+            }
+            Vertical(p1, p2) => {
+                if (p2.y - p1.y).abs() <= self.position {
+                    None
+                } else {
+                    self.position += 1;
+                    Some(Point { y: p1.y.min(p2.y) + self.position, x: p1.x })
+                }
+            }
+            Diagonal(p1, p2) => {
+                if (p2.x - p1.x).abs() <= self.position {
+                    None
+                } else {
+                    self.position += 1;
+                    Some(Point {
+                        x: p1.x + (p2.x - p1.x).signum() * self.position,
+                        y: p1.y + (p2.y - p1.y).signum() * self.position,
+                    })
+                }
+            }
+        }
+    }
+}
+
+impl IntoIterator for Line {
+    type Item = Point;
+    type IntoIter = PointIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        PointIterator::from(self)
+    }
+}
+
+impl Line {
+    pub fn from_coordinates(x1: i32, y1: i32, x2: i32, y2: i32) -> Line {
+        if x1 == x2 {
+            Vertical(Point { x: x1, y: y1 }, Point { x: x2, y: y2 })
+        } else if y1 == y2 {
+            Horizontal(Point { x: x1, y: y1 }, Point { x: x2, y: y2 })
+        } else {
+            Diagonal(Point { x: x1, y: y1 }, Point { x: x2, y: y2 })
+        }
+    }
+
+    /// Parses a line like `0,9 -> 5,9`
+    pub fn parse(line: Span) -> Result<Line, ParseError> {
+        let (start, end) = line.trim().split_once(" -> ")?;
+        let (x1, y1) = parse_point(start)?;
+        let (x2, y2) = parse_point(end)?;
+        Ok(Self::from_coordinates(x1, y1, x2, y2))
+    }
+}
+
+fn parse_point(point: Span) -> Result<(i32, i32), ParseError> {
+    let (x, y) = point.split_once(",")?;
+    Ok((x.parse("an x coordinate")?, y.parse("a y coordinate")?))
+}
+
+fn parse(content: &str) -> Result<Vec<Line>, ParseError> {
+    Span::lines(content).map(Line::parse).collect()
+}
+
+/// Counts the points that are covered by more than one line
+fn count_overlaps<I>(points: I) -> usize
+    where I: Iterator<Item=Point> {
+    let mut map: HashMap<Point, i32> = HashMap::new();
+    for point in points {
+        match map.entry(point) {
+            Entry::Occupied(mut o) => { *o.get_mut() += 1; }
+            Entry::Vacant(v) => { v.insert(0); }
+        }
+    }
+    map.values().filter(|&&i| i > 0).count()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const DEFAULT_INPUT: &'static str = "day05/src/input.txt";
+
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Vec<Line>, ParseError> {
+        parse(content)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<usize, NoAnswer> {
+        let it = lines.iter()
+            .filter(|l| !matches!(l, Diagonal(_, _)))
+            .flat_map(|&l| l.into_iter());
+        Ok(count_overlaps(it))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<usize, NoAnswer> {
+        let it = lines.iter().flat_map(|&l| l.into_iter());
+        Ok(count_overlaps(it))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day05;
+        sample: "test.txt" => 5, 12;
+    }
+}
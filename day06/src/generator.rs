@@ -0,0 +1,14 @@
+use aoc_common::{Generate, GeneratorConfig};
+use itertools::Itertools;
+use rand::Rng;
+
+use crate::Day06;
+
+/// 300 lanternfish per scale, aged 1 to 5 like in the real input
+impl Generate for Day06 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let ages = (0..config.scaled(300)).map(|_| rng.gen_range(1..=5)).join(",");
+        ages + "\n"
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_common::{debug, NoAnswer, ParseError, Solution, Span};
+
+mod generator;
+
+/// Number of lanternfish per days left until they create a new fish
+type Population = [u64; 9];
+
+fn simulate(mut population: Population, simulation_days: i32) -> u64 {
+    for day in 0..simulation_days {
+        debug!("Day {}: {}", day, population.iter().fold(String::new(), |a, b| a + " " +b.to_string().as_str()));
+        let babies = population[0];
+        (0..8).for_each(|i| population[i] = population[i+1]);
+        population[8] = babies;
+        population[6] += babies;
+    }
+    population.into_iter().sum::<u64>()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const DEFAULT_INPUT: &'static str = "day06/src/input.txt";
+
+    type Input = Population;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Population, ParseError> {
+        let line = Span::lines(content).next()
+            .ok_or_else(|| ParseError::at_end_of(content, "the ages of the lanternfish"))?;
+        let mut population: Population = [0; 9];
+        for age in line.trim().split(',') {
+            match age.trim().parse::<usize>("an age from 0 to 8")? {
+                i @ 0..=8 => population[i] += 1,
+                _ => return Err(age.trim().error("an age from 0 to 8")),
+            }
+        }
+        Ok(population)
+    }
+
+    fn part1(population: &Population) -> Result<u64, NoAnswer> {
+        Ok(simulate(*population, 80))
+    }
+
+    fn part2(population: &Population) -> Result<u64, NoAnswer> {
+        Ok(simulate(*population, 256))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day06;
+        sample: "test.txt" => 5934, 26984457539u64;
+    }
+}
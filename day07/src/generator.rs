@@ -0,0 +1,16 @@
+use aoc_common::{Generate, GeneratorConfig};
+use itertools::Itertools;
+use rand::Rng;
+
+use crate::Day07;
+
+/// 1000 crab positions per scale, more of them close to 0 like in the real input
+impl Generate for Day07 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let positions = (0..config.scaled(1000))
+            .map(|_| rng.gen_range(0..2000).min(rng.gen_range(0..2000)))
+            .join(",");
+        positions + "\n"
+    }
+}
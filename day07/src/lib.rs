@@ -0,0 +1,113 @@
+use aoc_common::{debug, progress, NoAnswer, ParseError, Solution, Span};
+use itertools::Itertools;
+
+mod generator;
+
+/// Returns the fuel cost for the crabs to move regarding the current boat position
+fn cost(boat_pos: usize, crab_pos: &[i32]) -> i64 {
+    crab_pos.iter().map(|&i| i64::abs(boat_pos as i64 - i as i64))
+        .map(|n| (n * n + n) / 2).sum()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const DEFAULT_INPUT: &'static str = "day07/src/input.txt";
+
+    /// The sorted crab positions
+    type Input = Vec<i32>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(content: &str) -> Result<Vec<i32>, ParseError> {
+        let line = Span::lines(content).next()
+            .ok_or_else(|| ParseError::at_end_of(content, "the crab positions"))?;
+        let positions: Vec<i32> = line.trim().split(',')
+            .map(|p| p.trim().parse::<i32>("a crab position"))
+            .collect::<Result<_, _>>()?;
+        Ok(positions.into_iter().sorted().collect_vec())
+    }
+
+    fn part1(it: &Vec<i32>) -> Result<i64, NoAnswer> {
+        let count = it.len();
+        // Possibly two means. But not in this case, so ignore it :)
+        // If this were an issue, select the mean with the most numbers
+        let mean = it[count/2];
+        let fuel: i64 = it.iter()
+            .map(|i| i64::abs((i - mean) as i64))
+            .sum();
+
+        progress!("Count: {}", count);
+        progress!("mean = {}", mean);
+        Ok(fuel)
+    }
+
+    fn part2(it: &Vec<i32>) -> Result<i64, NoAnswer> {
+        // There is one (discrete) minimum. Find it numerically
+        let mut upper: usize = *it.iter().max().unwrap() as usize;
+        let mut lower: usize = 0;
+
+        loop {
+            let mid = (upper + lower) / 2;
+            let grad_upper = cost(upper + 1, it) - cost(upper, it);
+            let grad_lower = cost(lower + 1, it) - cost(lower, it);
+            let grad_mid = cost(mid + 1, it) - cost(mid, it);
+            debug!("Grad Low: {}, Grad mid: {}, Grad high: {}", grad_lower, grad_mid, grad_upper);
+
+            if lower >= mid || upper <= mid {
+                progress!("Solution found!");
+                progress!("Lower: {}, Mid: {}, Upper: {}", lower, mid, upper);
+                let cost1 = cost(mid.saturating_sub(1), it);
+                let cost2 = cost(mid, it);
+                let cost3 = cost(mid + 1, it);
+                return Ok(cost1.min(cost2).min(cost3));
+            }
+
+            if grad_mid == 0 {
+                // The cost does not change here, so this is the minimum
+                progress!("Solution found at {}", mid);
+                return Ok(cost(mid, it));
+            }
+            if grad_mid.signum() * grad_lower.signum() < 0 {
+                // [lower, mid] is new interval
+                debug!("[Low, Mid]");
+                upper = mid;
+            } else if grad_mid.signum() * grad_upper.signum() < 0{
+                // [mid, upper] is new interval
+                debug!("[Mid, High]");
+                lower = mid;
+            } else {
+                // The cost only rises or falls in [lower, upper], so the minimum is at one end
+                progress!("Solution found at the end of [{}, {}]", lower, upper);
+                return Ok(cost(lower, it).min(cost(upper, it)));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day07;
+        sample: "test.txt" => 37, 168;
+    }
+
+    /// Tries every position, to check the bisection against
+    fn minimum_cost(positions: &[i32]) -> i64 {
+        (0..=*positions.last().unwrap() as usize).map(|p| cost(p, positions)).min().unwrap()
+    }
+
+    #[test]
+    fn test_part2_edge_cases() {
+        // A flat cost at the middle, a minimum at 1 and a cost that is flat between 0 and 1
+        for input in ["1,2,3,4,5,6,7,8", "0,2", "0,0,0,0,0,0,0,0,8", "5", "3,3,3"] {
+            let positions = Day07::parse(input).unwrap();
+            assert_eq!(Day07::part2(&positions), Ok(minimum_cost(&positions)), "for {}", input);
+        }
+        assert_eq!(Day07::part2(&Day07::parse("1,2,3,4,5,6,7,8").unwrap()), Ok(30));
+        assert_eq!(Day07::part2(&Day07::parse("0,2").unwrap()), Ok(2));
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day08;
+
+/// The segments of the digits 0 to 9 when wired correctly
+const DIGITS: [&str; 10] = ["abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg"];
+
+/// The scrambled segments of `digit`, listed in random order
+fn pattern<R: Rng>(wiring: &[char], digit: usize, rng: &mut R) -> String {
+    let mut segments = DIGITS[digit].chars()
+        .map(|c| wiring[(c as u8 - b'a') as usize])
+        .collect_vec();
+    segments.shuffle(rng);
+    segments.into_iter().collect()
+}
+
+/// 200 displays per scale, every one with its own random wiring
+impl Generate for Day08 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        for _ in 0..config.scaled(200) {
+            let mut wiring = "abcdefg".chars().collect_vec();
+            wiring.shuffle(&mut rng);
+
+            let mut digits = (0..10).collect_vec();
+            digits.shuffle(&mut rng);
+            let patterns = digits.iter().map(|&d| pattern(&wiring, d, &mut rng)).join(" ");
+            let output = (0..4).map(|_| {
+                let digit = rng.gen_range(0..10);
+                pattern(&wiring, digit, &mut rng)
+            }).join(" ");
+            writeln!(input, "{} | {}", patterns, output).unwrap();
+        }
+        input
+    }
+}
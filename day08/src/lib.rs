@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+
+use aoc_common::{debug, progress, NoAnswer, ParseError, Solution, Span};
+use itertools::Itertools;
+
+mod generator;
+
+struct Translator {
+    digits: Vec<HashSet<char>>,
+}
+
+impl Translator {
+
+    /// `None` if the patterns are not the ten digits with their segments mixed up
+    pub fn from_segment(segment: &[HashSet<char>]) -> Option<Translator> {
+        Some(Translator {
+            digits: Translator::parse_segment(segment)?
+        })
+    }
+
+    /// `None` if the segments are none of the digits
+    pub fn parse_digit(&self, segments: &HashSet<char>) -> Option<i32> {
+        let (pos, _) = self.digits.iter()
+            .find_position(|number| number.symmetric_difference(segments).count() == 0)?;
+        Some(pos as i32)
+    }
+
+    fn parse_segment(segments: &[HashSet<char>]) -> Option<Vec<HashSet<char>>> {
+        let one_segment: usize = 2;
+        let four_segment: usize = 4;
+        let seven_segment: usize = 3;
+        let eight_segment: usize = 7;
+        // Find unique numbers
+        let seg1 = segments.iter().filter(|s| s.len() == one_segment).exactly_one().ok()?;
+        let seg4 = segments.iter().filter(|s| s.len() == four_segment).exactly_one().ok()?;
+        let seg7 = segments.iter().filter(|s| s.len() == seven_segment).exactly_one().ok()?;
+        let seg8 = segments.iter().filter(|s| s.len() == eight_segment).exactly_one().ok()?;
+
+        // Find 'a'
+        let _a = seg7.iter().filter(|s7| !seg1.contains(s7)).exactly_one().ok()?;
+        // println!("a = {}", _a);
+        // Find '6'
+        let seg6 = segments.iter()
+            .filter(|s| s.intersection(seg1).count() == 1)
+            .filter(|s| s.len() == 6).exactly_one().ok()?;
+        // Find 'f'
+        let f = seg6.intersection(seg1).exactly_one().ok()?;
+        // println!("f = {}", f);
+        // Find 'c'
+        let c = seg1.iter().filter(|c| *c != f).exactly_one().ok()?;
+        // println!("c = {}", c);
+        // Find d: 4 without c and f = b and d
+        let bd: HashSet<char> = seg4.difference(&HashSet::from([*c, *f]))
+            .copied().collect();
+        let seg0 = segments.iter()
+            .filter(|seg| seg.len() == 6) // Filter to 0, 6, 9
+            .filter(|seg| seg.intersection(&bd).count() == 1).exactly_one().ok()?;// Filter to 0
+        let b = seg0.intersection(&bd).exactly_one().ok()?;
+        // println!("b = {}", b);
+        let d = bd.iter().filter(|c| *c != b).exactly_one().ok()?;
+        // println!("d = {}", d);
+
+        let seg2 = segments.iter()
+            .filter(|seg| seg.len() == 5)
+            .filter(|seg| !seg.contains(f)).exactly_one().ok()?;
+
+        let seg0 = segments.iter()
+            .filter(|seg| seg.len() == 6)
+            .filter(|seg| !seg.contains(d)).exactly_one().ok()?;
+        let seg3 = segments.iter()
+            .filter(|seg| seg.len() == 5)
+            .filter(|seg| seg.contains(f) && seg.contains(c)).exactly_one().ok()?;
+        let seg5 = segments.iter()
+            .filter(|seg| seg.len() == 5)
+            .filter(|seg| !seg.contains(c)).exactly_one().ok()?;
+        let seg9 = segments.iter()
+            .filter(|seg| seg.len() == 6)
+            .filter(|seg| seg.contains(c) && seg.contains(d)).exactly_one().ok()?;
+        Some(vec![seg0.clone(), seg1.clone(), seg2.clone(), seg3.clone(), seg4.clone(), seg5.clone(),
+                  seg6.clone(), seg7.clone(), seg8.clone(), seg9.clone()])
+    }
+}
+
+
+/// One line of the notes: the ten unique signal patterns and the four digits of the output
+pub struct Entry {
+    patterns: Vec<HashSet<char>>,
+    output: Vec<HashSet<char>>,
+}
+
+/// Parses `count` patterns made of the segments `a` to `g`
+fn parse_patterns(s: Span, count: usize) -> Result<Vec<HashSet<char>>, ParseError> {
+    let mut patterns = Vec::with_capacity(count);
+    for pattern in s.split_whitespace() {
+        if patterns.len() == count {
+            return Err(pattern.error(format!("{} patterns", count)));
+        }
+        let mut segments = HashSet::new();
+        for (c, span) in pattern.chars() {
+            if !('a'..='g').contains(&c) {
+                return Err(span.error("a segment from 'a' to 'g'"));
+            }
+            segments.insert(c);
+        }
+        patterns.push(segments);
+    }
+    if patterns.len() < count {
+        return Err(s.end().error(format!("{} patterns", count)));
+    }
+    Ok(patterns)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const DEFAULT_INPUT: &'static str = "day08/src/input.txt";
+
+    type Input = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(content: &str) -> Result<Vec<Entry>, ParseError> {
+        Span::lines(content).map(|l| {
+            let (patterns, output) = l.split_once("|")?;
+            Ok(Entry {
+                patterns: parse_patterns(patterns, 10)?,
+                output: parse_patterns(output, 4)?,
+            })
+        }).collect()
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Result<usize, NoAnswer> {
+        // Define number of segments for the uniquely identifiable numbers
+        let one_segment: usize = 2;
+        let four_segment: usize = 4;
+        let seven_segment: usize = 3;
+        let eight_segment: usize = 7;
+
+        let digit_occurrences = entries.iter()
+            .flat_map(|e| e.output.iter())
+            .map(|s| s.len())
+            .counts();
+
+        // A digit that does not occur at all has no entry
+        let found = |segments: usize| digit_occurrences.get(&segments).copied().unwrap_or(0);
+        progress!("Found {} ones", found(one_segment));
+        progress!("Found {} fours", found(four_segment));
+        progress!("Found {} sevens", found(seven_segment));
+        progress!("Found {} eights", found(eight_segment));
+        Ok(found(one_segment) + found(four_segment) + found(seven_segment) + found(eight_segment))
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Result<i64, NoAnswer> {
+        let mut total = 0;
+        for (line, entry) in entries.iter().enumerate() {
+            let unreadable = || NoAnswer::new(format!("the digits of entry {} cannot be told apart", line + 1));
+            let translator = Translator::from_segment(&entry.patterns).ok_or_else(unreadable)?;
+            let digits = entry.output.iter().map(|segments| translator.parse_digit(segments))
+                .collect::<Option<Vec<_>>>().ok_or_else(unreadable)?;
+            let output = digits.iter().rev().enumerate()
+                .map(|(i, d)| 10_i32.pow(i as u32) * d).sum::<i32>();
+            total += output as i64;
+            debug!("Output = {}", output);
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day08;
+        sample: "test.txt" => 26, 61229 + 5353;
+    }
+
+    #[test]
+    fn test_single_entry() {
+        // The example of the puzzle, which has no 1, 4, 7 or 8 in its output
+        let entries = Day08::parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf\n").unwrap();
+        assert_eq!(Day08::part1(&entries), Ok(0));
+        assert_eq!(Day08::part2(&entries), Ok(5353));
+    }
+
+    #[test]
+    fn test_unreadable_digits() {
+        let entries = Day08::parse("a b c d e f g ab abc abcd | a b c d\n").unwrap();
+        assert_eq!(Day08::part2(&entries), Err(NoAnswer::new("the digits of entry 1 cannot be told apart")));
+    }
+}
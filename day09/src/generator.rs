@@ -0,0 +1,24 @@
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use rand::Rng;
+
+use crate::Day09;
+
+/// A square heightmap of 100×100 points at scale 1 whose area grows with the scale.
+/// Enough of the points are 9s that the basins stay small, as in the real input
+impl Generate for Day09 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        let side = config.scaled_side(100);
+        for _ in 0..side {
+            for _ in 0..side {
+                let height = if rng.gen_bool(0.45) { 9 } else { rng.gen_range(0..9) };
+                write!(input, "{}", height).unwrap();
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
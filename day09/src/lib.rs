@@ -0,0 +1,133 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{progress, Grid, NoAnswer, ParseError, Solution};
+use itertools::Itertools;
+
+mod generator;
+
+type Board = Grid<u32>;
+
+struct LocalMinIterator<'a> {
+    index: usize,
+    board: &'a Board,
+}
+
+struct Basin {
+    size: u32
+}
+
+struct BasinIterator<'a> {
+    board: &'a Board,
+    // A set of indices of fields that still has to be searched
+    to_search: HashSet<usize>,
+}
+
+impl<'a> BasinIterator<'a> {
+    fn new(board: &'a Board) -> BasinIterator<'a> {
+        let to_search: HashSet<usize> = (0..board.len())
+            .filter(|&i| board.cells()[i] != 9)
+            .collect();
+
+        BasinIterator {
+            board,
+            to_search
+        }
+    }
+}
+impl Iterator for BasinIterator<'_> {
+    type Item = Basin;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let search = self.to_search.iter().next().cloned();
+        if let Some(start) = search {
+            self.to_search.remove(&start);
+            let mut basin = Basin { size: 1 };
+            let mut touched: VecDeque<usize>  = VecDeque::new();
+            touched.push_back(start);
+            while let Some(search) = touched.pop_front() {
+                let (x, y) = self.board.coords_of(search);
+                for (xn, yn) in self.board.neighbours4(x, y){
+                    let value = self.board[(xn, yn)];
+                    let index = self.board.index_of(xn, yn);
+                    if value != 9 && self.to_search.contains(&index){
+                        touched.push_back(index);
+                        basin.size += 1;
+                        self.to_search.remove(&index);
+                    }
+                }
+            }
+            Some(basin)
+        } else {
+            None
+        }
+    }
+}
+
+struct Minimum {
+    risk_level: u32,
+}
+
+impl Iterator for LocalMinIterator<'_> {
+    type Item = Minimum;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for i in self.index..self.board.len() {
+            let (x, y) = self.board.coords_of(i);
+            let value = self.board[(x, y)];
+            let is_lowest = !self.board.neighbours4(x, y)
+                .map(|(x, y)| self.board[(x, y)])
+                .any(|f| f <= value);
+
+            if is_lowest{
+                self.index = i + 1;
+                return Some(Minimum {
+                    risk_level: value + 1,
+                })
+            }
+        }
+
+        None
+    }
+}
+
+fn local_min_iterator(board: &Board) -> LocalMinIterator<'_> {
+    LocalMinIterator {
+        index: 0,
+        board,
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const DEFAULT_INPUT: &'static str = "day09/src/input.txt";
+
+    type Input = Board;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Board, ParseError> {
+        Board::parse_digits(content)
+    }
+
+    fn part1(board: &Board) -> Result<u32, NoAnswer> {
+        progress!("Total min points: {}", local_min_iterator(board).count());
+        Ok(local_min_iterator(board).map(|b| b.risk_level).sum::<u32>())
+    }
+
+    fn part2(board: &Board) -> Result<u64, NoAnswer> {
+        progress!("Total basins found: {}", BasinIterator::new(board).count());
+        Ok(BasinIterator::new(board).map(|b| b.size as u64).sorted().rev().take(3).product::<u64>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day09;
+        sample: "test.txt" => 15, 1134;
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_common::{Generate, GeneratorConfig};
+use rand::Rng;
+
+use crate::Day10;
+
+const OPENING: [char; 4] = ['(', '[', '{', '<'];
+const CLOSING: [char; 4] = [')', ']', '}', '>'];
+
+/// 100 lines of about 100 brackets per scale, about half of them corrupted and the rest
+/// incomplete. The chunks are nested at most 20 deep, so the completion scores fit into an i64
+impl Generate for Day10 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let mut input = String::new();
+        for line in 0..config.scaled(100) {
+            let length = rng.gen_range(90..110);
+            // At least one line has to be incomplete for part 2
+            let corrupted_at = (line > 0 && rng.gen_bool(0.5)).then(|| rng.gen_range(1..length));
+            let mut open: Vec<usize> = Vec::new();
+
+            for i in 0..length {
+                if Some(i) == corrupted_at && !open.is_empty() {
+                    let expected = *open.last().unwrap();
+                    let wrong = (expected + rng.gen_range(1..4)) % 4;
+                    input.push(CLOSING[wrong]);
+                    open.pop();
+                } else if open.is_empty() || (open.len() < 20 && rng.gen_bool(0.55)) {
+                    let bracket = rng.gen_range(0..4);
+                    input.push(OPENING[bracket]);
+                    open.push(bracket);
+                } else {
+                    input.push(CLOSING[open.pop().unwrap()]);
+                }
+            }
+            if open.is_empty() {
+                input.push(OPENING[rng.gen_range(0..4)]);
+            }
+            input.push('\n');
+        }
+        input
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::VecDeque;
+
+use aoc_common::{debug, progress, NoAnswer, ParseError, Solution, Span};
+
+use crate::Token::Closing;
+
+use crate::Type::{Bracket1, Bracket2, Bracket3, Bracket4};
+use crate::ValidationResult::{Incomplete, Invalid, TooMuchClosingBrackets, Valid};
+
+mod generator;
+
+#[derive(PartialOrd, PartialEq, Copy, Clone, Debug)]
+enum Type {
+    Bracket1,
+    Bracket2,
+    Bracket3,
+    Bracket4,
+}
+
+impl Type {
+    pub fn score(&self) -> i32{
+        match self {
+            Bracket1 => {57}
+            Bracket2 => {3}
+            Bracket3 => {1197}
+            Bracket4 => {25137}
+        }
+    }
+
+    /// The score for part2
+    pub fn score2(&self) -> i64{
+        match self {
+            Bracket1 => {2}
+            Bracket2 => {1}
+            Bracket3 => {3}
+            Bracket4 => {4}
+        }
+    }
+}
+#[derive(Debug, PartialOrd, PartialEq, Copy, Clone)]
+enum Token {
+    Opening(Type),
+    Closing(Type),
+    Undefined,
+}
+
+impl Token {
+    pub fn from_char(c: char) -> Token {
+        match c {
+            '[' => { Token::Opening(Bracket1) }
+            ']' => { Token::Closing(Bracket1) }
+            '(' => { Token::Opening(Bracket2) }
+            ')' => { Token::Closing(Bracket2) }
+            '{' => { Token::Opening(Bracket3) }
+            '}' => { Token::Closing(Bracket3) }
+            '<' => { Token::Opening(Bracket4) }
+            '>' => { Token::Closing(Bracket4) }
+            _ => { Token::Undefined }
+        }
+    }
+}
+
+struct TokenStream<I>
+    where I: Iterator<Item=char> {
+    iterator: I,
+}
+
+impl<I> TokenStream<I>
+    where I: Iterator<Item=char> {
+    fn from_iterator(iterator: I) -> TokenStream<I>
+    {
+        TokenStream {
+            iterator
+        }
+    }
+}
+
+impl<I> Iterator for TokenStream<I>
+    where I: Iterator<Item=char> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator.next().map(Token::from_char)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+struct ValidationError {
+    reality: Token
+}
+
+#[derive(Debug, Clone, PartialOrd, PartialEq)]
+enum ValidationResult {
+    Valid,
+    Invalid(ValidationError),
+    TooMuchClosingBrackets,
+    Incomplete(VecDeque<Type>),
+    EncounteredUndefinedToken
+}
+
+fn validate<I>(tokens: TokenStream<I>) -> ValidationResult
+    where I: Iterator<Item=char> {
+    let mut stack: VecDeque<Type> = VecDeque::new();
+    for token in tokens {
+        match token {
+            Token::Opening(t) => {
+                stack.push_front(t);
+            }
+            Token::Closing(t) => {
+                if let Some(pop) = stack.pop_front() {
+                    if pop != t {
+                        return Invalid(ValidationError {
+                            reality: Closing(t)
+                        });
+                    }
+                } else {
+                    return TooMuchClosingBrackets;
+                }
+            }
+            Token::Undefined => {
+                return ValidationResult::EncounteredUndefinedToken;
+            }
+        }
+    }
+
+    if stack.is_empty() {
+        Valid
+    } else {
+        Incomplete(stack)
+    }
+}
+
+fn validate_line(line: &str) -> ValidationResult {
+    let stream = TokenStream::from_iterator(line.chars());
+    let valid = validate(stream);
+    debug!("Valid: {:?}", valid);
+    valid
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const DEFAULT_INPUT: &'static str = "day10/src/input.txt";
+
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(content: &str) -> Result<Vec<String>, ParseError> {
+        Span::lines(content).map(|line| {
+            match line.chars().find(|(c, _)| !"()[]{}<>".contains(*c)) {
+                Some((_, span)) => Err(span.error("a bracket")),
+                None => Ok(line.as_str().to_string()),
+            }
+        }).collect()
+    }
+
+    /// The syntax error score of all corrupted lines
+    fn part1(lines: &Vec<String>) -> Result<i64, NoAnswer> {
+        let mut points_part1 = 0;
+        for line in lines {
+            if let Invalid(error) = validate_line(line) {
+                if let Closing(t) = error.reality {
+                   points_part1 += t.score() as i64;
+                }
+            }
+        }
+        Ok(points_part1)
+    }
+
+    /// The middle score of all incomplete lines
+    fn part2(lines: &Vec<String>) -> Result<i64, NoAnswer> {
+        let mut incomplete_scores: Vec<i64> = Vec::new();
+        for line in lines {
+            if let Incomplete(stack) = validate_line(line) {
+                let mut points: i64 = 0;
+                for t in stack {
+                    points *= 5;
+                    points += t.score2();
+                }
+                incomplete_scores.push(points);
+            }
+        }
+
+        progress!("Number of incomplete lines: {}", incomplete_scores.len());
+        if incomplete_scores.is_empty() {
+            return Err(NoAnswer::new("no line is incomplete"));
+        }
+        incomplete_scores.sort();
+        Ok(incomplete_scores[incomplete_scores.len()/2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day10;
+        sample: "test.txt" => 26397, 288957;
+    }
+
+    #[test]
+    fn test_no_incomplete_line() {
+        let lines = Day10::parse("{([(<{}[<>[]}>{[]{[(<()>\n()\n").unwrap();
+        assert_eq!(Day10::part1(&lines), Ok(1197));
+        assert_eq!(Day10::part2(&lines), Err(NoAnswer::new("no line is incomplete")));
+    }
+}
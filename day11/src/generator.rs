@@ -0,0 +1,31 @@
+use aoc_common::{Generate, GeneratorConfig};
+use rand::Rng;
+
+use crate::{step, Board, Day11};
+
+/// Steps after which a grid that did not synchronize is thrown away
+const MAX_STEPS: usize = 1000;
+
+fn synchronizes(mut board: Board) -> bool {
+    (0..MAX_STEPS).any(|_| step(&mut board) == board.len())
+}
+
+/// A square grid of octopuses, 10×10 at scale 1, whose area grows with the scale.
+/// Part 2 never ends for a grid that does not synchronize. Random grids do so less often the
+/// larger they are, so larger grids are tried with fewer energy levels, which synchronize sooner
+impl Generate for Day11 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let side = config.scaled_side(10);
+        for max_energy in [10, 8, 6, 4] {
+            for _ in 0..3 {
+                let board = Board::from_vec(side, (0..side * side).map(|_| rng.gen_range(0..max_energy)).collect());
+                if synchronizes(board.clone()) {
+                    return board.to_string();
+                }
+            }
+        }
+        // All octopuses start with the same energy and flash together right away
+        Board::filled(side, side, rng.gen_range(0..10)).to_string()
+    }
+}
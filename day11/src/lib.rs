@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use aoc_common::{debug, Grid, NoAnswer, ParseError, Solution};
+
+mod generator;
+
+type Board = Grid<u32>;
+
+/// Simulates a single step and returns how many octopus flashed
+fn step(board: &mut Board) -> usize {
+    let mut flashing: VecDeque<usize> = VecDeque::new();
+    board.iter_mut().for_each(|i| *i += 1);
+    board.iter().enumerate().filter(|(_, &i)| i > 9)
+        .for_each(|(index, _)| flashing.push_back(index));
+    let mut flashing_octopus = flashing.len();
+
+    while let Some(position) = flashing.pop_front() {
+        let (x, y) = board.coords_of(position);
+        for (xn, yn) in board.neighbours8(x, y) {
+            board[(xn, yn)] += 1;
+            // Flashes for the first time as it reaches level greater 9
+            if board[(xn, yn)] == 10 {
+                flashing.push_back(board.index_of(xn, yn));
+                flashing_octopus += 1;
+            }
+        }
+    }
+    board.iter_mut().filter(|i| **i > 9)
+        .for_each(|i| *i = 0);
+    flashing_octopus
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const DEFAULT_INPUT: &'static str = "day11/src/input.txt";
+
+    type Input = Board;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(content: &str) -> Result<Board, ParseError> {
+        Board::parse_digits(content)
+    }
+
+    /// Total number of flashes in the first 100 steps
+    fn part1(board: &Board) -> Result<usize, NoAnswer> {
+        let mut board = board.clone();
+        let mut flashing_octopus = 0;
+        debug!("Before:\n{}", board);
+
+        // Simulate steps
+        for i in 0..100 {
+            flashing_octopus += step(&mut board);
+            debug!("\nAfter Step {}:\n{}", i+1, board);
+        }
+        Ok(flashing_octopus)
+    }
+
+    /// The first step during which all octopus flash
+    fn part2(board: &Board) -> Result<usize, NoAnswer> {
+        let mut board = board.clone();
+        debug!("Before:\n{}", board);
+
+        let mut step_count = 0;
+        // Simulate steps
+        while board.iter().any(|&f| f > 0) {
+            step(&mut board);
+            step_count += 1;
+            debug!("\nAfter Step {}:\n{}", step_count, board);
+        }
+        Ok(step_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day11;
+        small: "test.txt" => 259, 6;
+        sample: "test2.txt" => 1656, 195;
+    }
+}
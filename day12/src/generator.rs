@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+
+use crate::Day12;
+
+/// A unique name of at least two letters for the `index`th cave
+fn cave_name(mut index: usize, big: bool) -> String {
+    let first = if big { b'A' } else { b'a' };
+    let mut name = Vec::new();
+    while name.len() < 2 || index > 0 {
+        name.push(first + (index % 26) as u8);
+        index /= 26;
+    }
+    String::from_utf8(name).unwrap()
+}
+
+/// A cave system like the real one with 6 small and 2 big caves at scale 1.
+/// The number of paths grows exponentially with the number of caves, so every scale
+/// only adds one small cave and every third scale a big one. Large scales find more paths
+/// than an `i32` holds, the solver counts them as `u64`, which is enough for every path it can walk.
+/// Big caves are never connected to each other, which would allow infinitely many paths
+impl Generate for Day12 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let small = (0..5 + config.scale).map(|i| cave_name(i, false)).collect_vec();
+        let big = (0..1 + config.scale.div_ceil(3)).map(|i| cave_name(i, true)).collect_vec();
+
+        let mut edges: HashSet<(String, String)> = HashSet::new();
+        let mut connect = |a: &str, b: &str| {
+            if !edges.contains(&(b.to_string(), a.to_string())) {
+                edges.insert((a.to_string(), b.to_string()));
+            }
+        };
+        for cave in ["start", "end"] {
+            for other in small.choose_multiple(&mut rng, 2).chain(big.choose(&mut rng)) {
+                connect(cave, other);
+            }
+        }
+        for cave in &big {
+            for other in small.choose_multiple(&mut rng, 3) {
+                connect(cave, other);
+            }
+        }
+        for _ in 0..small.len() {
+            let pair = small.choose_multiple(&mut rng, 2).collect_vec();
+            connect(pair[0], pair[1]);
+        }
+
+        let mut edges = edges.into_iter().collect_vec();
+        // HashSet iteration is not deterministic, the input should only depend on the seed
+        edges.sort();
+        edges.shuffle(&mut rng);
+        let mut input = String::new();
+        for (a, b) in edges {
+            writeln!(input, "{}-{}", a, b).unwrap();
+        }
+        input
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::hash_map::Entry;
+
+use aoc_common::{debug, trace, NoAnswer, ParseError, Solution, Span};
+use itertools::Itertools;
+
+mod generator;
+
+pub struct Graph {
+    nodes: Vec<String>,
+    edges: Vec<Option<usize>>,
+    big_nodes: HashSet<usize>,
+    edge_stride: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Graph {
+    fn new(edge_input: Vec<(&str, &str)>) -> Graph {
+        let unique_nodes1 = edge_input.iter()
+            .map(|(s1, _)| s1);
+        let unique_nodes2 = edge_input.iter()
+            .map(|(_, s2)| s2);
+        let edge_stride = *unique_nodes1.clone().chain(unique_nodes2.clone())
+            .counts().values().max().unwrap();
+
+        let unique = unique_nodes1.chain(unique_nodes2)
+            .unique()
+            .map(|s| s.to_string())
+            .collect_vec();
+
+        let mut mapping: HashMap<String, usize> = HashMap::new();
+        unique.iter().enumerate().for_each(|(i, s)| { mapping.insert(s.to_string(), i); });
+
+        // Look for big nodes
+        let mut big_nodes: HashSet<usize> = HashSet::new();
+        unique.iter().filter(|s| is_big(s))
+            .for_each(|s| { big_nodes.insert(mapping[s]); });
+
+        let start = mapping["start"];
+        let end = mapping["end"];
+
+        let edges: Vec<Option<usize>> = vec![None; unique.len() * edge_stride];
+        let mut graph = Graph {
+            nodes: unique,
+            big_nodes,
+            edges,
+            edge_stride,
+            start,
+            end,
+        };
+
+        for edge in edge_input {
+            let n1 = mapping.get(edge.0).unwrap();
+            let n2 = mapping.get(edge.1).unwrap();
+            graph.add_edge(*n1, *n2);
+            graph.add_edge(*n2, *n1);
+        }
+
+        graph
+    }
+
+    /// Adds a directed edge from node1 --> node2
+    fn add_edge(&mut self, node1: usize, node2: usize) {
+        let free = self.edges[node1 * self.edge_stride..(node1 + 1) * self.edge_stride]
+            .iter_mut().find(|n| n.is_none());
+        *free.unwrap() = Some(node2);
+    }
+
+    fn neighbours_of(&self, node: usize) -> &[Option<usize>] {
+        &self.edges[node * self.edge_stride..(node + 1) * self.edge_stride]
+    }
+
+    fn num_paths(&self, node: usize, visited: &mut HashSet<usize>) -> u64 {
+        trace!("Visiting {}", self.nodes[node]);
+        if node == self.end {
+            return 1;
+        }
+
+        if !self.big_nodes.contains(&node) {
+            visited.insert(node);
+        }
+
+        let mut number_of_paths = 0;
+        for neighbour in self.neighbours_of(node).iter().flatten() {
+            if !visited.contains(neighbour) {
+                number_of_paths += self.num_paths(*neighbour, visited);
+            }
+        }
+
+        visited.remove(&node);
+        number_of_paths
+    }
+
+    fn num_paths_second_visit(&self, node: usize, visited: &mut HashMap<usize, u8>, path: &mut VecDeque<usize>) -> u64 {
+        path.push_back(node);
+        if node == self.end {
+            debug!("{}", path.iter().map(|&i| &self.nodes[i]).join(","));
+            path.pop_back();
+            return 1;
+        }
+
+        if !self.big_nodes.contains(&node) {
+            match visited.entry(node) {
+                Entry::Occupied(mut o) => {*o.get_mut() += 1;}
+                Entry::Vacant(o) => {o.insert(1);}
+            }
+        }
+
+        let mut number_of_paths = 0;
+        for neighbour in self.neighbours_of(node).iter().flatten() {
+            let has_second_visit = visited.values().contains(&2);
+            if let Entry::Occupied(entry) = visited.entry(*neighbour) {
+                if *entry.get() == 0 || (*entry.get() < 2 && !has_second_visit) {
+                    number_of_paths += self.num_paths_second_visit(*neighbour, visited, path);
+                }
+            } else {
+                number_of_paths += self.num_paths_second_visit(*neighbour, visited, path);
+            }
+        }
+
+        if let Entry::Occupied(mut entry) = visited.entry(node) {
+            *entry.get_mut() -= 1;
+        }
+        path.pop_back();
+        number_of_paths
+    }
+
+    pub fn paths_start_end(&self) -> u64 {
+        self.num_paths(self.start, &mut Default::default())
+    }
+
+    pub fn paths_second_visit(&self) -> u64 {
+        let mut map: HashMap<usize, u8> = HashMap::new();
+        // A value != 2 but bigger 2
+        map.insert(self.start, 100);
+        self.num_paths_second_visit(self.start, &mut map, &mut Default::default())
+    }
+
+}
+
+fn is_big(cave: &str) -> bool {
+    cave.chars().next().is_some_and(char::is_uppercase)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const DEFAULT_INPUT: &'static str = "day12/src/input.txt";
+
+    type Input = Graph;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(content: &str) -> Result<Graph, ParseError> {
+        let mut edges: Vec<(&str, &str)> = Vec::new();
+        for line in Span::lines(content) {
+            let (n1, n2) = line.trim().split_once("-")?;
+            for node in [n1, n2] {
+                if node.is_empty() || !node.as_str().chars().all(|c| c.is_ascii_alphabetic()) {
+                    return Err(node.error("a cave name"));
+                }
+            }
+            // Two connected big caves would allow infinitely many paths
+            if is_big(n1.as_str()) && is_big(n2.as_str()) {
+                return Err(line.trim().error("an edge with at least one small cave"));
+            }
+            edges.push((n1.as_str(), n2.as_str()));
+        }
+        for required in ["start", "end"] {
+            if !edges.iter().any(|&(n1, n2)| n1 == required || n2 == required) {
+                return Err(ParseError::at_end_of(content, format!("a connection to '{}'", required)));
+            }
+        }
+        Ok(Graph::new(edges))
+    }
+
+    fn part1(graph: &Graph) -> Result<u64, NoAnswer> {
+        Ok(graph.paths_start_end())
+    }
+
+    fn part2(graph: &Graph) -> Result<u64, NoAnswer> {
+        Ok(graph.paths_second_visit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day12;
+        small: "test.txt" => 10, 36;
+        larger: "test2.txt" => 19, 103;
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use aoc_common::{Generate, GeneratorConfig};
+use itertools::Itertools;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::Day13;
+
+/// Size of the code that is readable after all folds
+const CODE_WIDTH: usize = 40;
+const CODE_HEIGHT: usize = 6;
+
+/// The fold positions from the first to the last fold. Every fold halves the paper,
+/// the last one leaves `size` lines
+fn fold_positions(size: usize, folds: usize) -> Vec<usize> {
+    let mut positions = Vec::new();
+    let mut size = size;
+    for _ in 0..folds {
+        positions.push(size);
+        size = 2 * size + 1;
+    }
+    positions.reverse();
+    positions
+}
+
+/// One of the positions that end up at `position` after folding along all `folds`
+fn unfold<R: Rng>(position: usize, folds: &[usize], rng: &mut R) -> usize {
+    folds.iter().rev().fold(position, |p, &fold| if rng.gen() { 2 * fold - p } else { p })
+}
+
+/// A random code of 40×6 dots that is folded 5 times along x and 6 times along y like the real
+/// input. Every doubling of the scale adds another fold, 800 dots per scale are spread over the paper
+impl Generate for Day13 {
+    fn generate(config: &GeneratorConfig) -> String {
+        let mut rng = config.rng();
+        let extra_folds = config.scale.ilog2() as usize;
+        let folds_x = fold_positions(CODE_WIDTH, 5 + extra_folds.div_ceil(2));
+        let folds_y = fold_positions(CODE_HEIGHT, 6 + extra_folds / 2);
+
+        let code = (0..CODE_WIDTH).cartesian_product(0..CODE_HEIGHT)
+            .filter(|_| rng.gen_bool(0.4))
+            .collect_vec();
+        let count = config.scaled(800);
+        let mut dots = HashSet::new();
+        let mut ordered = Vec::new();
+        // The code may have fewer positions on the paper than dots are requested
+        for _ in 0..count * 10 {
+            if ordered.len() == count {
+                break;
+            }
+            let &(x, y) = code.choose(&mut rng).unwrap();
+            let dot = (unfold(x, &folds_x, &mut rng), unfold(y, &folds_y, &mut rng));
+            if dots.insert(dot) {
+                ordered.push(dot);
+            }
+        }
+
+        let mut input = String::new();
+        for (x, y) in ordered {
+            writeln!(input, "{},{}", x, y).unwrap();
+        }
+        input.push('\n');
+        let folds = folds_x.iter().map(|x| format!("x={}", x))
+            .interleave(folds_y.iter().map(|y| format!("y={}", y)));
+        for fold in folds {
+            writeln!(input, "fold along {}", fold).unwrap();
+        }
+        input
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use aoc_common::{debug, progress, Grid, NoAnswer, ParseError, Solution, Span};
+use itertools::Itertools;
+
+mod generator;
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Parses a dot like `6,10`
+    fn parse(s: Span) -> Result<Self, ParseError> {
+        let (x, y) = s.trim().split_once(",")?;
+        Ok(Self::new(parse_position(x, "a non-negative x coordinate")?, parse_position(y, "a non-negative y coordinate")?))
+    }
+}
+
+/// The paper starts at 0, so neither dots nor folds can be left of or above it
+fn parse_position(s: Span, expected: &str) -> Result<i32, ParseError> {
+    match s.parse::<i32>(expected)? {
+        value if value >= 0 => Ok(value),
+        _ => Err(s.error(expected)),
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum Fold {
+    X(i32),
+    Y(i32),
+}
+
+impl Display for Fold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fold::X(x) => write!(f, "x={}", x),
+            Fold::Y(y) => write!(f, "y={}", y),
+        }
+    }
+}
+
+impl Fold {
+    /// Parses an instruction like `fold along y=7`
+    fn parse(s: Span) -> Result<Self, ParseError> {
+        let (axis, value) = s.trim().strip_prefix("fold along ")?.split_once("=")?;
+        let value = parse_position(value, "a non-negative fold position")?;
+        match axis.as_str() {
+            "x" => Ok(Self::X(value)),
+            "y" => Ok(Self::Y(value)),
+            _ => Err(axis.error("the axis 'x' or 'y'")),
+        }
+    }
+    fn fold(&self, p: Point) -> Point {
+        match self {
+            Fold::X(x) => Point::new(Self::fold_along_axis(p.x, *x), p.y),
+            Fold::Y(y) => Point::new(p.x, Self::fold_along_axis(p.y, *y)),
+        }
+    }
+
+    fn fold_along_axis(point_position: i32, fold_position: i32) -> i32 {
+        // Fold onto the upper (left) quadrant
+        if point_position <= fold_position {
+            point_position
+        } else {
+            let distance = point_position - fold_position;
+            point_position - 2 * distance
+        }
+    }
+}
+
+/// The transparent paper, `true` marks a dot
+type ConsoleDisplay = Grid<bool>;
+
+/// The dots on the paper and the fold instructions
+pub struct Manual {
+    points: Vec<Point>,
+    fold_expressions: Vec<Fold>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const DEFAULT_INPUT: &'static str = "day13/src/input.txt";
+
+    type Input = Manual;
+    type Answer1 = usize;
+    type Answer2 = Grid<char>;
+
+    fn parse(input: &str) -> Result<Manual, ParseError> {
+        let fold_expressions = Span::lines(input)
+            .skip_while(|line| !line.trim().is_empty())
+            .skip(1).map(Fold::parse).collect::<Result<Vec<_>, _>>()?;
+        debug!("Fold expressions: {:?}", fold_expressions);
+
+        let lines = Span::lines(input).take_while(|l| !l.trim().is_empty()).collect_vec();
+        let points = lines.iter().map(|&line| Point::parse(line)).collect::<Result<Vec<_>, _>>()?;
+        if points.is_empty() || fold_expressions.is_empty() {
+            return Err(ParseError::at_end_of(input, "dots followed by an empty line and fold instructions"));
+        }
+        // A dot further away from a fold than the paper reaches on the other side would end up outside of it
+        for (line, &point) in lines.iter().zip(&points) {
+            let mut folded = point;
+            for fold in &fold_expressions {
+                folded = fold.fold(folded);
+                if folded.x < 0 || folded.y < 0 {
+                    return Err(line.error(format!("a dot that stays on the paper when folded along {}", fold)));
+                }
+            }
+        }
+        Ok(Manual { points, fold_expressions })
+    }
+
+    /// Number of visible dots after the first fold
+    fn part1(manual: &Manual) -> Result<usize, NoAnswer> {
+        let first_fold = *manual.fold_expressions.first().unwrap();
+        progress!("First fold: {}", first_fold);
+        Ok(manual.points.iter().map(|p| first_fold.fold(*p)).unique().count())
+    }
+
+    /// The code that is readable after all folds
+    fn part2(manual: &Manual) -> Result<Grid<char>, NoAnswer> {
+        let folded = manual.points.iter().map(|&p| {
+            manual.fold_expressions.iter().fold(p, |p, fold| fold.fold(p))
+        }).unique().collect::<Vec<_>>();
+        progress!("Folded points: {:?}", folded.len());
+
+        let xmax = folded.iter().map(|p| p.x).max().unwrap();
+        let ymax = folded.iter().map(|p| p.y).max().unwrap();
+        let mut display = ConsoleDisplay::filled(xmax as usize + 1, ymax as usize + 1, false);
+        for p in folded {
+            display[(p.x as usize, p.y as usize)] = true;
+        }
+        Ok(display.map(|&dot| if dot { '#' } else { '.' }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc_common::sample_tests! {
+        Day13;
+        sample: "testinput.txt" => 17, "#####\n#...#\n#...#\n#...#\n#####";
+    }
+
+    #[test]
+    fn test_dots_outside_of_the_paper() {
+        let error = Day13::parse("1,1\n-1,0\n\nfold along y=1\n").err().unwrap();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 1, "a non-negative x coordinate"));
+
+        let error = Day13::parse("1,1\n\nfold along x=-2\n").err().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (3, "a non-negative fold position"));
+
+        // 7 is more than twice as far from the fold at 2 as 0
+        let error = Day13::parse("1,1\n7,0\n\nfold along x=2\n").err().unwrap();
+        assert_eq!((error.line, error.expected.as_str()), (2, "a dot that stays on the paper when folded along x=2"));
+    }
+}
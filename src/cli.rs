@@ -0,0 +1,163 @@
+use aoc_common::log::Verbosity;
+use aoc_common::{GeneratorConfig, InputSource, OutputFormat, Part};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc list                            List all registered days
+    aoc run <day|all> [options]         Solve one or all days
+    aoc generate <day> [options]        Print a random input for a day
+
+Options:
+    -p, --part <1|2>        Only solve the given part
+    -i, --input <file|->    Read the input from a file or stdin (-) instead of the
+                            input.txt of the day. Only allowed for a single day
+    -v, -vv, -vvv           Print progress, debug or trace output of the solvers to stderr
+    --json                  Print one JSON record per part with the day, part, answer,
+                            times in nanoseconds and the SHA-256 of the input
+
+Generator options:
+    --scale <n>             Size of the input, 1 is about the size of the real input
+    --seed <n>              Seed of the random numbers, the same seed gives the same input";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    List,
+    Run { selection: Selection, parts: Vec<Part>, input: InputSource, verbosity: Verbosity, format: OutputFormat },
+    Generate { day: u8, config: GeneratorConfig },
+    Help,
+}
+
+pub fn parse_args<I>(mut args: I) -> Result<Command, String>
+    where I: Iterator<Item=String> {
+    let command = match args.next() {
+        None => return Ok(Command::Help),
+        Some(command) => command,
+    };
+
+    match command.as_str() {
+        "list" => Ok(Command::List),
+        "help" | "--help" | "-h" => Ok(Command::Help),
+        "run" => {
+            let selection = match args.next().as_deref() {
+                None => return Err("Missing day to run".to_string()),
+                Some("all") => Selection::All,
+                Some(day) => Selection::Day(day.parse()
+                    .map_err(|_| format!("'{}' is not a day", day))?),
+            };
+
+            let mut parts = Part::BOTH.to_vec();
+            let mut input = InputSource::Default;
+            let mut verbosity = Verbosity::Quiet;
+            let mut format = OutputFormat::Text;
+            while let Some(arg) = args.next() {
+                if let Some(v) = Verbosity::from_flag(&arg) {
+                    verbosity = v;
+                    continue;
+                }
+                match arg.as_str() {
+                    "--part" | "-p" => {
+                        parts = match args.next().as_deref() {
+                            Some("1") => vec![Part::One],
+                            Some("2") => vec![Part::Two],
+                            other => return Err(format!("Expected part 1 or 2, found {:?}", other)),
+                        };
+                    }
+                    "--json" => format = OutputFormat::Json,
+                    "--input" | "-i" => {
+                        input = match args.next() {
+                            Some(arg) => InputSource::from_arg(&arg),
+                            None => return Err("Missing input file".to_string()),
+                        };
+                    }
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            if selection == Selection::All && input != InputSource::Default {
+                return Err("A single input can not be used for all days".to_string());
+            }
+            Ok(Command::Run { selection, parts, input, verbosity, format })
+        }
+        "generate" => {
+            let day = match args.next() {
+                None => return Err("Missing day to generate an input for".to_string()),
+                Some(day) => day.parse().map_err(|_| format!("'{}' is not a day", day))?,
+            };
+
+            let mut config = GeneratorConfig::default();
+            while let Some(arg) = args.next() {
+                let value = args.next();
+                match arg.as_str() {
+                    "--scale" => {
+                        config.scale = value.as_deref().and_then(|v| v.parse().ok()).filter(|&s| s > 0)
+                            .ok_or_else(|| format!("Expected a scale of at least 1, found {:?}", value))?;
+                    }
+                    "--seed" => {
+                        config.seed = value.as_deref().and_then(|v| v.parse().ok())
+                            .ok_or_else(|| format!("Expected a seed, found {:?}", value))?;
+                    }
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            Ok(Command::Generate { day, config })
+        }
+        _ => Err(format!("Unknown command '{}'", command)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        parse_args(args.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(parse("run 7 --part 2"), Ok(Command::Run {
+            selection: Selection::Day(7),
+            parts: vec![Part::Two],
+            input: InputSource::Default,
+            verbosity: Verbosity::Quiet,
+            format: OutputFormat::Text,
+        }));
+        assert_eq!(parse("run all --json"), Ok(Command::Run {
+            selection: Selection::All,
+            parts: Part::BOTH.to_vec(),
+            input: InputSource::Default,
+            verbosity: Verbosity::Quiet,
+            format: OutputFormat::Json,
+        }));
+        assert!(parse("run seven").is_err());
+        assert!(parse("run 7 --part 3").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse("run 13 -i day13/src/testinput.txt"), Ok(Command::Run {
+            selection: Selection::Day(13),
+            parts: Part::BOTH.to_vec(),
+            input: InputSource::File("day13/src/testinput.txt".into()),
+            verbosity: Verbosity::Quiet,
+            format: OutputFormat::Text,
+        }));
+        assert!(matches!(parse("run 1 --input -"), Ok(Command::Run { input: InputSource::Stdin, .. })));
+        assert!(parse("run all --input input.txt").is_err());
+        assert!(matches!(parse("run 4 -vv"), Ok(Command::Run { verbosity: Verbosity::Debug, .. })));
+    }
+
+    #[test]
+    fn test_parse_generate() {
+        assert_eq!(parse("generate 12 --scale 3 --seed 42"),
+                   Ok(Command::Generate { day: 12, config: GeneratorConfig::new(3, 42) }));
+        assert_eq!(parse("generate 5"), Ok(Command::Generate { day: 5, config: GeneratorConfig::default() }));
+        assert!(parse("generate 5 --scale 0").is_err());
+        assert!(parse("generate 5 --seed").is_err());
+    }
+}
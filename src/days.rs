@@ -0,0 +1,74 @@
+use aoc_common::Day;
+
+/// All days the runner knows about, ordered by day
+pub const DAYS: [Day; 13] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_common::testing::KnownAnswers;
+    use aoc_common::{GeneratorConfig, InputSource, Part};
+
+    use super::*;
+
+    /// Checks the real inputs against `answers.txt` in the workspace root. The file is not
+    /// part of the repository since everyone has their own inputs, so the test passes without it
+    #[test]
+    fn real_inputs_match_known_answers() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt"));
+        let answers = match KnownAnswers::load(path) {
+            Some(answers) => answers.unwrap_or_else(|error| panic!("Malformed answers file, {}", error)),
+            None => {
+                eprintln!("No answers file at {}, skipping the real inputs", path.display());
+                return;
+            }
+        };
+
+        for day in DAYS {
+            let parts: Vec<Part> = Part::BOTH.into_iter()
+                .filter(|&part| answers.get(day.day, part).is_some()).collect();
+            if parts.is_empty() {
+                continue;
+            }
+            let input = day.read_input(&InputSource::Default).unwrap();
+            let report = day.run(&input, &parts).unwrap();
+            for part in report.parts {
+                let answer = part.answer
+                    .unwrap_or_else(|reason| panic!("No answer for day {:02} part {}, {}", day.day, part.part, reason));
+                assert_eq!(answer.to_string().trim_end(), answers.get(day.day, part.part).unwrap(),
+                           "Wrong answer for day {:02} part {}", day.day, part.part);
+            }
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_solvable() {
+        let config = GeneratorConfig::new(1, 2021);
+        for day in DAYS {
+            let input = day.generate(&config);
+            assert_eq!(input, day.generate(&config), "Day {:02} is not deterministic", day.day);
+            if let Err(error) = day.run(&input, &Part::BOTH) {
+                panic!("Day {:02} generated a malformed input, {}", day.day, error);
+            }
+        }
+    }
+}
@@ -1 +1,91 @@
-fn main() {}
\ No newline at end of file
+use std::process::exit;
+use std::time::Instant;
+
+use aoc_common::output::summary_table;
+use aoc_common::solution::DayReport;
+use aoc_common::{log, Day, InputSource, OutputFormat, Part};
+use rayon::prelude::*;
+
+use crate::cli::{Command, Selection};
+
+mod cli;
+mod days;
+
+/// Reads the input of a day and solves the given parts
+fn solve(day: &Day, input: &InputSource, parts: &[Part]) -> Result<DayReport, String> {
+    let content = day.read_input(input)
+        .map_err(|error| format!("Day {:02}: {}", day.day, error))?;
+    day.run(&content, parts)
+        .map_err(|error| format!("Day {:02}: Malformed input, {}", day.day, error))
+}
+
+fn main() {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            exit(2);
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", cli::USAGE),
+        Command::List => {
+            for day in &days::DAYS {
+                println!("Day {:02}", day.day);
+            }
+        }
+        Command::Run { selection, parts, input, verbosity, format } => {
+            log::set_verbosity(verbosity);
+            let selected = match selection {
+                Selection::All => days::DAYS.iter().collect(),
+                Selection::Day(day) => match days::find(day) {
+                    Some(day) => vec![day],
+                    None => {
+                        eprintln!("Day {} is not registered, see 'aoc list'", day);
+                        exit(1);
+                    }
+                },
+            };
+
+            // The days share no state, so they are solved in parallel and printed in order afterwards
+            let start = Instant::now();
+            let results: Vec<_> = selected.par_iter()
+                .map(|day| solve(day, &input, &parts))
+                .collect();
+            let wall_time = start.elapsed();
+
+            let mut reports = Vec::new();
+            let mut failed = false;
+            for result in results {
+                match result {
+                    Ok(report) => {
+                        print!("{}", report.render(format));
+                        failed |= !report.is_complete();
+                        reports.push(report);
+                    }
+                    Err(message) => {
+                        eprintln!("{}", message);
+                        failed = true;
+                    }
+                }
+            }
+
+            if selection == Selection::All && format == OutputFormat::Text {
+                print!("\n{}", summary_table(&reports));
+                println!("Wall time: {:.3} ms ({} threads)", wall_time.as_secs_f64() * 1000.0,
+                         rayon::current_num_threads());
+            }
+            if failed {
+                exit(1);
+            }
+        }
+        Command::Generate { day, config } => match days::find(day) {
+            Some(day) => print!("{}", day.generate(&config)),
+            None => {
+                eprintln!("Day {} is not registered, see 'aoc list'", day);
+                exit(1);
+            }
+        },
+    }
+}